@@ -4,11 +4,12 @@ use std::time::Instant;
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
-use crate::config::{CommentDefault, Config};
+use crate::config::{CommentDefault, Config, QuitGuard};
+use crate::fuzzy;
 use crate::git::RemoteInfo;
 use crate::keybinds::Keybinds;
-use crate::markdown;
-use crate::pr_diff::{DiffKind, parse_patch};
+use crate::markdown::{self, MarkdownLink, TicketLinker};
+use crate::pr_diff::{DiffKind, hunk_excerpt, parse_patch};
 use crate::store::{CommentRow, IssueRow, LocalRepoRow};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,6 +35,8 @@ pub enum AppAction {
     PickIssue,
     OpenInBrowser,
     CheckoutPullRequest,
+    CopyIssueChecklist,
+    CopyPullRequestReviewComment,
     MergePullRequest,
     OpenLinkedPullRequestInBrowser,
     OpenLinkedPullRequestInTui,
@@ -152,12 +155,20 @@ pub enum LinkedPickerTarget {
     PullRequestBrowser,
     IssueTui,
     IssueBrowser,
+    ExternalLink,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct LinkedPickerOption {
-    number: i64,
+    number: Option<i64>,
     title: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedItemSummary {
+    pub title: String,
+    pub state: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -377,6 +388,11 @@ struct SyncState {
     pull_request_review_comments_sync_requested: bool,
     sync_requested: bool,
     rescan_requested: bool,
+    github_status_checking: bool,
+    github_status_check_requested: bool,
+    github_status_checked_at: Option<Instant>,
+    github_status_notice: Option<String>,
+    polling_was_paused: bool,
 }
 
 #[derive(Debug, Default)]
@@ -385,6 +401,7 @@ struct LinkedState {
     issues: HashMap<i64, Vec<i64>>,
     pull_request_lookups: HashSet<i64>,
     issue_lookups: HashSet<i64>,
+    summaries: HashMap<i64, LinkedItemSummary>,
     navigation_origin: Option<(i64, WorkItemMode)>,
 }
 
@@ -537,6 +554,14 @@ struct PresetState {
     choice: usize,
 }
 
+#[derive(Debug, Default)]
+struct QuitState {
+    guard: QuitGuard,
+    pending_mutations: usize,
+    confirm_visible: bool,
+    wait_deadline: Option<Instant>,
+}
+
 mod editor;
 mod metadata;
 mod preset;
@@ -551,6 +576,7 @@ mod state;
 
 mod accessors;
 mod input;
+mod quit;
 
 pub struct App {
     should_quit: bool,
@@ -580,11 +606,18 @@ pub struct App {
     editor_flow: EditorFlowState,
     metadata_picker: MetadataPickerState,
     preset: PresetState,
+    quit: QuitState,
+    ticket_linker: TicketLinker,
 }
 
 impl App {
     pub fn new(config: Config) -> Self {
         let keybinds = Keybinds::from_overrides(&config.keybinds);
+        let quit = QuitState {
+            guard: config.quit_guard(),
+            ..QuitState::default()
+        };
+        let ticket_linker = TicketLinker::new(&config.ticket_links);
         Self {
             should_quit: false,
             config,
@@ -613,6 +646,8 @@ impl App {
             editor_flow: EditorFlowState::default(),
             metadata_picker: MetadataPickerState::default(),
             preset: PresetState::default(),
+            quit,
+            ticket_linker,
         }
     }
 }
@@ -49,6 +49,21 @@ impl App {
             .collect::<Vec<&IssueRow>>()
     }
 
+    pub fn issue_checklist_markdown(&self) -> String {
+        self.issues_for_view()
+            .iter()
+            .map(|issue| {
+                let mark = if issue_state_is_closed(issue.state.as_str()) {
+                    "x"
+                } else {
+                    " "
+                };
+                format!("- [{}] #{} {}", mark, issue.number, issue.title.trim())
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     pub fn selected_issue_row(&self) -> Option<&IssueRow> {
         let issue_index = *self
             .search
@@ -142,6 +157,10 @@ impl App {
         &self.config.comment_defaults
     }
 
+    pub fn github_status_notice(&self) -> Option<&str> {
+        self.sync.github_status_notice.as_deref()
+    }
+
     pub fn theme_name(&self) -> Option<&str> {
         self.config.theme.as_deref()
     }
@@ -231,6 +250,10 @@ impl App {
         self.sync.repo_pull_request_mergeable
     }
 
+    pub fn configured_repo_color(&self, owner: &str, repo: &str) -> Option<&str> {
+        self.config.repo_color(owner, repo)
+    }
+
     pub fn repo_label_color(&self, label: &str) -> Option<&str> {
         let key = label.trim().to_ascii_lowercase();
         self.repo_label_colors.get(&key).map(String::as_str)
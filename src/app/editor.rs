@@ -122,34 +122,26 @@ impl App {
                     self.pull_request.pending_review_target = None;
                     self.set_view(self.editor_flow.cancel_view);
                 }
-                KeyCode::Tab => {
-                    if self.comment_editor.create_issue_confirm_visible() {
-                        self.comment_editor
-                            .toggle_create_issue_confirm_submit_selected();
-                    }
-                }
-                KeyCode::BackTab => {
-                    if self.comment_editor.create_issue_confirm_visible() {
-                        self.comment_editor
-                            .toggle_create_issue_confirm_submit_selected();
-                    }
-                }
-                KeyCode::Enter if key.modifiers.contains(KeyModifiers::SHIFT) => {
-                    if self.comment_editor.mode().allows_multiline()
-                        && !self.comment_editor.create_issue_title_focused()
-                        && !self.comment_editor.create_issue_confirm_visible()
-                    {
-                        self.comment_editor.newline()
-                    }
+                KeyCode::Tab | KeyCode::BackTab
+                    if self.comment_editor.create_issue_confirm_visible() =>
+                {
+                    self.comment_editor
+                        .toggle_create_issue_confirm_submit_selected();
                 }
-                KeyCode::Enter if key.modifiers.contains(KeyModifiers::ALT) => {
-                    if self.comment_editor.mode().allows_multiline()
+                KeyCode::Enter
+                    if key
+                        .modifiers
+                        .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT)
+                        && self.comment_editor.mode().allows_multiline()
                         && !self.comment_editor.create_issue_title_focused()
-                        && !self.comment_editor.create_issue_confirm_visible()
-                    {
-                        self.comment_editor.newline()
-                    }
+                        && !self.comment_editor.create_issue_confirm_visible() =>
+                {
+                    self.comment_editor.newline()
                 }
+                KeyCode::Enter
+                    if key
+                        .modifiers
+                        .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT) => {}
                 KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     if self.comment_editor.mode() == EditorMode::CreateIssue {
                         self.comment_editor.focus_create_issue_body();
@@ -161,11 +153,13 @@ impl App {
                         self.comment_editor.newline()
                     }
                 }
-                KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    if self.comment_editor.mode() == EditorMode::CreateIssue {
-                        self.comment_editor.focus_create_issue_title();
-                    }
+                KeyCode::Char('k')
+                    if key.modifiers.contains(KeyModifiers::CONTROL)
+                        && self.comment_editor.mode() == EditorMode::CreateIssue =>
+                {
+                    self.comment_editor.focus_create_issue_title();
                 }
+                KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {}
                 KeyCode::Char('j') if self.comment_editor.create_issue_confirm_visible() => {
                     self.comment_editor
                         .set_create_issue_confirm_submit_selected(true);
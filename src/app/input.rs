@@ -6,6 +6,12 @@ impl App {
             Some(key) => key,
             None => return,
         };
+        let quit_key =
+            key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+        if self.quit_guard_active() && !quit_key {
+            self.cancel_quit();
+            return;
+        }
         if matches!(self.view, View::CommentPresetName | View::CommentEditor) {
             self.handle_editor_key(key);
             return;
@@ -58,7 +64,7 @@ impl App {
 
         match key.code {
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.should_quit = true
+                self.request_quit()
             }
             KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.open_repo_picker();
@@ -225,18 +231,23 @@ impl App {
             KeyCode::Char('p') if self.view == View::PullRequestFiles => {
                 self.cycle_pull_request_review_comment(false);
             }
-            KeyCode::Char('h') if self.view == View::PullRequestFiles => {
-                if self.pull_request.pull_request_review_focus == PullRequestReviewFocus::Diff {
-                    self.pull_request.pull_request_review_side = ReviewSide::Left;
-                    self.sync_selected_pull_request_review_comment();
-                }
+            KeyCode::Char('h')
+                if self.view == View::PullRequestFiles
+                    && self.pull_request.pull_request_review_focus
+                        == PullRequestReviewFocus::Diff =>
+            {
+                self.pull_request.pull_request_review_side = ReviewSide::Left;
+                self.sync_selected_pull_request_review_comment();
             }
-            KeyCode::Char('l') if self.view == View::PullRequestFiles => {
-                if self.pull_request.pull_request_review_focus == PullRequestReviewFocus::Diff {
-                    self.pull_request.pull_request_review_side = ReviewSide::Right;
-                    self.sync_selected_pull_request_review_comment();
-                }
+            KeyCode::Char('l')
+                if self.view == View::PullRequestFiles
+                    && self.pull_request.pull_request_review_focus
+                        == PullRequestReviewFocus::Diff =>
+            {
+                self.pull_request.pull_request_review_side = ReviewSide::Right;
+                self.sync_selected_pull_request_review_comment();
             }
+            KeyCode::Char('h') | KeyCode::Char('l') if self.view == View::PullRequestFiles => {}
             KeyCode::Char('V') if self.view == View::PullRequestFiles => {
                 self.toggle_pull_request_visual_mode();
             }
@@ -357,6 +368,15 @@ impl App {
                 }
                 self.interaction.action = Some(AppAction::OpenLinkedPullRequestInBrowser);
             }
+            KeyCode::Char('L')
+                if key.modifiers.contains(KeyModifiers::SHIFT)
+                    && matches!(
+                        self.view,
+                        View::Issues | View::IssueDetail | View::IssueComments
+                    ) =>
+            {
+                self.open_link_picker();
+            }
             KeyCode::Char('P')
                 if key.modifiers.contains(KeyModifiers::SHIFT)
                     && matches!(
@@ -376,6 +396,14 @@ impl App {
                 }
                 self.interaction.action = Some(AppAction::OpenLinkedPullRequestInTui);
             }
+            KeyCode::Char('y') if key.modifiers.is_empty() && self.view == View::Issues => {
+                self.interaction.action = Some(AppAction::CopyIssueChecklist);
+            }
+            KeyCode::Char('y')
+                if key.modifiers.is_empty() && self.view == View::PullRequestFiles =>
+            {
+                self.interaction.action = Some(AppAction::CopyPullRequestReviewComment);
+            }
             KeyCode::Char('v')
                 if matches!(
                     self.view,
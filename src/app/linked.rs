@@ -137,6 +137,26 @@ impl App {
         }
     }
 
+    pub fn set_linked_item_summary(&mut self, number: i64, title: &str, state: &str) {
+        self.linked.summaries.insert(
+            number,
+            LinkedItemSummary {
+                title: title.to_string(),
+                state: state.to_string(),
+            },
+        );
+    }
+
+    pub fn linked_item_summary(&self, number: i64) -> Option<LinkedItemSummary> {
+        if let Some(issue) = self.issues.iter().find(|issue| issue.number == number) {
+            return Some(LinkedItemSummary {
+                title: issue.title.clone(),
+                state: issue.state.clone(),
+            });
+        }
+        self.linked.summaries.get(&number).cloned()
+    }
+
     pub fn open_linked_picker(
         &mut self,
         cancel_view: View,
@@ -147,12 +167,11 @@ impl App {
         let options = numbers
             .into_iter()
             .map(|number| LinkedPickerOption {
-                number,
+                number: Some(number),
                 title: self
-                    .issues
-                    .iter()
-                    .find(|issue| issue.number == number)
-                    .map(|issue| issue.title.clone()),
+                    .linked_item_summary(number)
+                    .map(|summary| summary.title),
+                url: None,
             })
             .collect::<Vec<LinkedPickerOption>>();
         if options.is_empty() {
@@ -173,11 +192,59 @@ impl App {
         self.set_view(View::LinkedPicker);
     }
 
+    pub(super) fn open_link_picker(&mut self) {
+        let links = self.current_issue_links();
+        if links.is_empty() {
+            self.status = "No links found".to_string();
+            return;
+        }
+
+        let cancel_view = self.view;
+        self.clear_linked_picker_state();
+        self.linked_picker.options = links
+            .into_iter()
+            .map(|link| LinkedPickerOption {
+                number: None,
+                title: Some(link.label),
+                url: Some(link.url),
+            })
+            .collect::<Vec<LinkedPickerOption>>();
+        self.linked_picker.target = Some(LinkedPickerTarget::ExternalLink);
+        self.linked_picker.cancel_view = cancel_view;
+        self.set_view(View::LinkedPicker);
+    }
+
+    pub fn current_issue_links(&self) -> Vec<MarkdownLink> {
+        let issue = match self.current_or_selected_issue() {
+            Some(issue) => issue,
+            None => return Vec::new(),
+        };
+        let mut bodies = vec![issue.body.as_str()];
+        if matches!(self.view, View::IssueDetail | View::IssueComments) {
+            bodies.extend(self.comments.iter().map(|comment| comment.body.as_str()));
+        }
+
+        let mut links: Vec<MarkdownLink> = Vec::new();
+        for body in bodies {
+            for link in markdown::render_with_links(body, &self.ticket_linker).links {
+                if links.iter().any(|existing| existing.url == link.url) {
+                    continue;
+                }
+                links.push(link);
+            }
+        }
+        links
+    }
+
+    pub fn ticket_linker(&self) -> &TicketLinker {
+        &self.ticket_linker
+    }
+
     pub fn linked_picker_numbers(&self) -> Vec<i64> {
         self.linked_picker
             .options
             .iter()
-            .map(|option| option.number)
+            .filter_map(|option| option.number)
             .collect::<Vec<i64>>()
     }
 
@@ -185,7 +252,7 @@ impl App {
         self.linked_picker
             .options
             .iter()
-            .map(|option| linked_picker_label(option.number, option.title.as_deref()))
+            .map(linked_picker_label)
             .collect::<Vec<String>>()
     }
 
@@ -197,7 +264,14 @@ impl App {
         self.linked_picker
             .options
             .get(self.linked_picker.selected)
-            .map(|option| option.number)
+            .and_then(|option| option.number)
+    }
+
+    pub fn selected_linked_picker_url(&self) -> Option<&str> {
+        self.linked_picker
+            .options
+            .get(self.linked_picker.selected)
+            .and_then(|option| option.url.as_deref())
     }
 
     pub fn linked_picker_target(&self) -> Option<LinkedPickerTarget> {
@@ -246,6 +320,7 @@ impl App {
             Some(LinkedPickerTarget::PullRequestBrowser) => "Open Linked Pull Request (Web)",
             Some(LinkedPickerTarget::IssueTui) => "Open Linked Issue",
             Some(LinkedPickerTarget::IssueBrowser) => "Open Linked Issue (Web)",
+            Some(LinkedPickerTarget::ExternalLink) => "Open Link (Web)",
             None => "Choose Linked Item",
         }
     }
@@ -331,8 +406,19 @@ fn push_unique(values: &mut Vec<i64>, value: i64) {
     values.push(value);
 }
 
-fn linked_picker_label(number: i64, title: Option<&str>) -> String {
-    let title = title.map(str::trim).filter(|title| !title.is_empty());
+fn linked_picker_label(option: &LinkedPickerOption) -> String {
+    let title = option
+        .title
+        .as_deref()
+        .map(str::trim)
+        .filter(|title| !title.is_empty());
+    if let Some(url) = option.url.as_deref() {
+        return match title {
+            Some(title) if title != url => format!("{}  {}", title, url),
+            _ => url.to_string(),
+        };
+    }
+    let number = option.number.unwrap_or_default();
     if let Some(title) = title {
         return format!("#{}  {}", number, title);
     }
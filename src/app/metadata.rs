@@ -20,21 +20,13 @@ impl App {
     }
 
     pub fn filtered_label_indices(&self) -> Vec<usize> {
-        let query = self.metadata_picker.label_query.trim().to_ascii_lowercase();
-        self.metadata_picker
-            .label_options
-            .iter()
-            .enumerate()
-            .filter_map(|(index, label)| {
-                if query.is_empty() {
-                    return Some(index);
-                }
-                if label.to_ascii_lowercase().contains(query.as_str()) {
-                    return Some(index);
-                }
-                None
-            })
-            .collect::<Vec<usize>>()
+        fuzzy::rank(
+            self.metadata_picker.label_query.as_str(),
+            self.metadata_picker
+                .label_options
+                .iter()
+                .map(String::as_str),
+        )
     }
 
     pub fn assignee_options(&self) -> &[String] {
@@ -56,25 +48,13 @@ impl App {
     }
 
     pub fn filtered_assignee_indices(&self) -> Vec<usize> {
-        let query = self
-            .metadata_picker
-            .assignee_query
-            .trim()
-            .to_ascii_lowercase();
-        self.metadata_picker
-            .assignee_options
-            .iter()
-            .enumerate()
-            .filter_map(|(index, assignee)| {
-                if query.is_empty() {
-                    return Some(index);
-                }
-                if assignee.to_ascii_lowercase().contains(query.as_str()) {
-                    return Some(index);
-                }
-                None
-            })
-            .collect::<Vec<usize>>()
+        fuzzy::rank(
+            self.metadata_picker.assignee_query.as_str(),
+            self.metadata_picker
+                .assignee_options
+                .iter()
+                .map(String::as_str),
+        )
     }
 
     pub fn open_label_picker(
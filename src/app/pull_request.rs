@@ -1,5 +1,7 @@
 use super::*;
 
+const REVIEW_QUOTE_CONTEXT_LINES: usize = 6;
+
 impl App {
     pub fn pull_request_files(&self) -> &[PullRequestFile] {
         &self.pull_request.pull_request_files
@@ -186,6 +188,56 @@ impl App {
         comments.first().copied()
     }
 
+    pub fn selected_pull_request_review_comment_quote(&self) -> Option<String> {
+        let comment = self.selected_pull_request_review_comment()?;
+        let owner = self.context.owner.as_deref()?;
+        let repo = self.context.repo.as_deref()?;
+        let pull_number = self.context.issue_number?;
+        let permalink = format!(
+            "https://github.com/{}/{}/pull/{}#discussion_r{}",
+            owner, repo, pull_number, comment.id
+        );
+
+        let mut sections = vec![format!(
+            "**@{}** on [`{}:{}`]({}):",
+            comment.author, comment.path, comment.line, permalink
+        )];
+        let hunk = self
+            .pull_request
+            .pull_request_files
+            .iter()
+            .find(|file| file.filename == comment.path)
+            .and_then(|file| file.patch.as_deref())
+            .and_then(|patch| {
+                hunk_excerpt(
+                    patch,
+                    comment.line,
+                    comment.side == ReviewSide::Left,
+                    REVIEW_QUOTE_CONTEXT_LINES,
+                )
+            });
+        if let Some(hunk) = hunk {
+            let fence = markdown_fence_for(hunk.as_str());
+            sections.push(format!("{}diff\n{}\n{}", fence, hunk, fence));
+        }
+        sections.push(
+            comment
+                .body
+                .trim_end()
+                .lines()
+                .map(|line| {
+                    if line.is_empty() {
+                        ">".to_string()
+                    } else {
+                        format!("> {}", line)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        );
+        Some(sections.join("\n\n"))
+    }
+
     pub fn update_pull_request_review_comment_body_by_id(&mut self, comment_id: i64, body: &str) {
         for comment in &mut self.pull_request.pull_request_review_comments {
             if comment.id != comment_id {
@@ -690,3 +742,12 @@ impl App {
         self.pull_request.selected_pull_request_review_comment_id = comment_id;
     }
 }
+
+fn markdown_fence_for(content: &str) -> String {
+    let longest_run = content
+        .split(|ch| ch != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    "`".repeat(longest_run.max(2) + 1)
+}
@@ -0,0 +1,85 @@
+use super::*;
+use std::time::Duration;
+
+const QUIT_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+impl App {
+    pub fn set_pending_mutations(&mut self, count: usize) {
+        self.quit.pending_mutations = count;
+    }
+
+    pub fn pending_mutations(&self) -> usize {
+        self.quit.pending_mutations
+    }
+
+    pub fn quit_guard_active(&self) -> bool {
+        self.quit.confirm_visible || self.quit.wait_deadline.is_some()
+    }
+
+    pub(super) fn request_quit(&mut self) {
+        self.request_quit_at(Instant::now());
+    }
+
+    pub fn request_quit_at(&mut self, now: Instant) {
+        if self.quit.guard == QuitGuard::Off
+            || self.quit.pending_mutations == 0
+            || self.quit_guard_active()
+        {
+            self.should_quit = true;
+            return;
+        }
+
+        match self.quit.guard {
+            QuitGuard::Wait => self.quit.wait_deadline = Some(now + QUIT_WAIT_TIMEOUT),
+            QuitGuard::Prompt | QuitGuard::Off => self.quit.confirm_visible = true,
+        }
+        self.refresh_quit_guard_status(now);
+    }
+
+    pub(super) fn cancel_quit(&mut self) {
+        self.quit.confirm_visible = false;
+        self.quit.wait_deadline = None;
+        self.set_transient_status("Quit cancelled", Duration::from_secs(2));
+    }
+
+    pub fn tick_quit_guard(&mut self) {
+        self.tick_quit_guard_at(Instant::now());
+    }
+
+    pub fn tick_quit_guard_at(&mut self, now: Instant) {
+        if !self.quit_guard_active() {
+            return;
+        }
+        if self.quit.pending_mutations == 0 {
+            self.should_quit = true;
+            return;
+        }
+        if let Some(deadline) = self.quit.wait_deadline
+            && now >= deadline
+        {
+            self.should_quit = true;
+            return;
+        }
+        self.refresh_quit_guard_status(now);
+    }
+
+    fn refresh_quit_guard_status(&mut self, now: Instant) {
+        let count = self.quit.pending_mutations;
+        let noun = if count == 1 { "change" } else { "changes" };
+        let quit = self.keybind_label("quit");
+        let status = match self.quit.wait_deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(now).as_secs_f64().ceil();
+                format!(
+                    "Waiting for {} background {} ({}s) • {} quit now • any other key stays",
+                    count, noun, remaining, quit
+                )
+            }
+            None => format!(
+                "{} background {} still running • {} quit anyway • any other key stays",
+                count, noun, quit
+            ),
+        };
+        self.set_status(status);
+    }
+}
@@ -21,17 +21,25 @@ impl App {
     }
 
     pub(super) fn rebuild_issue_filter(&mut self) {
-        let query = self.search.issue_query.trim().to_ascii_lowercase();
+        let query = self.normalized_issue_query();
+        // Fuzzy-only title matches rank after every substring match.
+        let mut fuzzy_only = HashSet::new();
         self.search.filtered_issue_indices = self
             .issues
             .iter()
             .enumerate()
             .filter_map(|(index, issue)| {
-                if self.work_item_mode.matches(issue)
-                    && self.issue_filter.matches(issue)
-                    && self.assignee_filter_matches(issue)
-                    && Self::issue_matches_query(issue, query.as_str())
+                if !self.work_item_mode.matches(issue)
+                    || !self.issue_filter.matches(issue)
+                    || !self.assignee_filter_matches(issue)
                 {
+                    return None;
+                }
+                if Self::issue_matches_query(issue, query.as_str(), false) {
+                    return Some(index);
+                }
+                if Self::issue_matches_query(issue, query.as_str(), true) {
+                    fuzzy_only.insert(index);
                     return Some(index);
                 }
                 None
@@ -41,6 +49,12 @@ impl App {
         self.search
             .filtered_issue_indices
             .sort_by(|left_index, right_index| {
+                let tier_cmp = fuzzy_only
+                    .contains(left_index)
+                    .cmp(&fuzzy_only.contains(right_index));
+                if tier_cmp != std::cmp::Ordering::Equal {
+                    return tier_cmp;
+                }
                 let left = self.issues.get(*left_index);
                 let right = self.issues.get(*right_index);
                 match (left, right) {
@@ -63,15 +77,19 @@ impl App {
         }
     }
 
-    pub(super) fn issue_matches_query(issue: &IssueRow, query: &str) -> bool {
+    fn normalized_issue_query(&self) -> String {
+        self.search.issue_query.trim().to_lowercase()
+    }
+
+    pub(super) fn issue_matches_query(issue: &IssueRow, query: &str, fuzzy_title: bool) -> bool {
         if query.is_empty() {
             return true;
         }
 
-        let title = issue.title.to_ascii_lowercase();
-        let body = issue.body.to_ascii_lowercase();
-        let labels = issue.labels.to_ascii_lowercase();
-        let assignees = issue.assignees.to_ascii_lowercase();
+        let title = issue.title.to_lowercase();
+        let body = issue.body.to_lowercase();
+        let labels = issue.labels.to_lowercase();
+        let assignees = issue.assignees.to_lowercase();
         let number = issue.number.to_string();
         let state = issue.state.to_ascii_lowercase();
 
@@ -112,9 +130,20 @@ impl App {
                 || labels.contains(token)
                 || assignees.contains(token)
                 || number.contains(token)
+                || (fuzzy_title && fuzzy::fuzzy_match(token, title.as_str()).is_some())
         })
     }
 
+    /// Free-text terms of the issue query, normalized the same way the
+    /// filter sees them so highlights line up with what matched.
+    pub fn issue_query_text(&self) -> String {
+        self.normalized_issue_query()
+            .split_whitespace()
+            .filter(|token| !token.contains(':') && !token.starts_with('#'))
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
     pub(super) fn cycle_assignee_filter(&mut self, forward: bool) {
         let options = self.assignee_filter_options();
         if options.is_empty() {
@@ -296,25 +325,19 @@ impl App {
     }
 
     pub(super) fn rebuild_repo_picker_filter(&mut self) {
-        let query = self.search.repo_query.trim().to_ascii_lowercase();
-        self.search.filtered_repo_indices = self
+        let haystacks = self
             .repos
             .iter()
-            .enumerate()
-            .filter_map(|(index, repo)| {
-                if query.is_empty() {
-                    return Some(index);
-                }
-                let haystack = format!(
-                    "{} {} {} {} {}",
-                    repo.owner, repo.repo, repo.path, repo.remote_name, repo.url
+            .map(|repo| {
+                format!(
+                    "{}/{} {} {}",
+                    repo.owner, repo.repo, repo.path, repo.remote_name
                 )
-                .to_ascii_lowercase();
-                if haystack.contains(query.as_str()) {
-                    return Some(index);
-                }
-                None
             })
-            .collect::<Vec<usize>>();
+            .collect::<Vec<String>>();
+        self.search.filtered_repo_indices = fuzzy::rank(
+            self.search.repo_query.as_str(),
+            haystacks.iter().map(String::as_str),
+        );
     }
 }
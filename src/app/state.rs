@@ -1,6 +1,8 @@
 use super::*;
 use std::time::{Duration, Instant};
 
+const GITHUB_STATUS_CHECK_INTERVAL: Duration = Duration::from_secs(120);
+
 impl App {
     pub fn should_quit(&self) -> bool {
         self.should_quit
@@ -239,6 +241,76 @@ impl App {
         requested
     }
 
+    pub fn request_github_status_check(&mut self) {
+        self.request_github_status_check_at(Instant::now());
+    }
+
+    pub fn request_github_status_check_at(&mut self, now: Instant) {
+        if self.sync.github_status_checking {
+            return;
+        }
+        if let Some(checked_at) = self.sync.github_status_checked_at
+            && now.saturating_duration_since(checked_at) < GITHUB_STATUS_CHECK_INTERVAL
+        {
+            return;
+        }
+        self.sync.github_status_check_requested = true;
+    }
+
+    pub fn take_github_status_check_request(&mut self) -> bool {
+        let requested = self.sync.github_status_check_requested;
+        self.sync.github_status_check_requested = false;
+        requested
+    }
+
+    pub fn set_github_status_checking(&mut self, checking: bool) {
+        self.sync.github_status_checking = checking;
+    }
+
+    pub fn set_github_status_notice(&mut self, notice: Option<String>) {
+        self.set_github_status_notice_at(notice, Instant::now());
+    }
+
+    pub fn set_github_status_notice_at(&mut self, notice: Option<String>, now: Instant) {
+        self.sync.github_status_checking = false;
+        self.sync.github_status_checked_at = Some(now);
+        self.sync.github_status_notice = notice;
+    }
+
+    pub fn clear_github_status_notice(&mut self) {
+        self.sync.github_status_notice = None;
+    }
+
+    /// Background polling holds off while a comment is being written or a
+    /// diff range is being selected, so lists and status stay put.
+    pub fn polling_paused(&self) -> bool {
+        self.view == View::CommentEditor
+            || (self.view == View::PullRequestFiles && self.pull_request.pull_request_visual_mode)
+    }
+
+    /// Returns true once when a pause ends, after queueing a refresh of
+    /// whatever the current view shows.
+    pub fn take_polling_resumed(&mut self) -> bool {
+        let paused = self.polling_paused();
+        let resumed = self.sync.polling_was_paused && !paused;
+        self.sync.polling_was_paused = paused;
+        if !resumed {
+            return false;
+        }
+
+        self.request_sync();
+        if matches!(
+            self.view,
+            View::IssueDetail | View::IssueComments | View::PullRequestFiles
+        ) {
+            self.request_comment_sync();
+        }
+        if self.view == View::PullRequestFiles {
+            self.request_pull_request_review_comments_sync();
+        }
+        true
+    }
+
     pub fn take_sync_request(&mut self) -> bool {
         let requested = self.sync.sync_requested;
         self.sync.sync_requested = false;
@@ -262,6 +334,7 @@ impl App {
         self.linked.issues.clear();
         self.linked.pull_request_lookups.clear();
         self.linked.issue_lookups.clear();
+        self.linked.summaries.clear();
         self.linked.navigation_origin = None;
         self.clear_linked_picker_state();
         self.reset_pull_request_state();
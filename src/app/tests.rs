@@ -1,7 +1,7 @@
 pub(super) use super::{
     App, AppAction, EditorMode, Focus, IssueFilter, LinkedPickerTarget, MouseTarget,
-    PullRequestFile, PullRequestReviewFocus, PullRequestReviewTarget, ReviewSide, View,
-    WorkItemMode,
+    PullRequestFile, PullRequestReviewComment, PullRequestReviewFocus, PullRequestReviewTarget,
+    ReviewSide, View, WorkItemMode,
 };
 pub(super) use crate::config::Config;
 pub(super) use crate::store::{CommentRow, IssueRow, LocalRepoRow};
@@ -287,6 +287,71 @@ fn v_triggers_checkout_pull_request_action() {
     assert_eq!(app.take_action(), Some(AppAction::CheckoutPullRequest));
 }
 
+#[test]
+fn y_triggers_copy_issue_checklist_action() {
+    let mut app = App::new(Config::default());
+    app.set_view(View::Issues);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+
+    assert_eq!(app.take_action(), Some(AppAction::CopyIssueChecklist));
+}
+
+#[test]
+fn issue_checklist_markdown_uses_filtered_items() {
+    let mut app = App::new(Config::default());
+    app.set_view(View::Issues);
+    app.set_issues(vec![
+        IssueRow {
+            id: 1,
+            repo_id: 1,
+            number: 12,
+            state: "open".to_string(),
+            title: "Fix login redirect".to_string(),
+            body: String::new(),
+            labels: String::new(),
+            assignees: String::new(),
+            comments_count: 0,
+            updated_at: None,
+            is_pr: false,
+        },
+        IssueRow {
+            id: 2,
+            repo_id: 1,
+            number: 9,
+            state: "open".to_string(),
+            title: "Add dark theme ".to_string(),
+            body: String::new(),
+            labels: String::new(),
+            assignees: String::new(),
+            comments_count: 0,
+            updated_at: None,
+            is_pr: false,
+        },
+        IssueRow {
+            id: 3,
+            repo_id: 1,
+            number: 4,
+            state: "closed".to_string(),
+            title: "Crash on start".to_string(),
+            body: String::new(),
+            labels: String::new(),
+            assignees: String::new(),
+            comments_count: 0,
+            updated_at: None,
+            is_pr: false,
+        },
+    ]);
+
+    assert_eq!(
+        app.issue_checklist_markdown(),
+        "- [ ] #12 Fix login redirect\n- [ ] #9 Add dark theme"
+    );
+
+    app.set_issue_filter(IssueFilter::Closed);
+    assert_eq!(app.issue_checklist_markdown(), "- [x] #4 Crash on start");
+}
+
 #[test]
 fn shift_o_triggers_open_linked_pull_request_in_browser_action() {
     let mut app = App::new(Config::default());
@@ -1,4 +1,5 @@
 use super::*;
+use std::time::{Duration, Instant};
 
 #[test]
 fn ctrl_a_resets_assignee_filter_to_all() {
@@ -201,6 +202,53 @@ fn m_triggers_pull_request_review_comment_in_review_view() {
     assert_eq!(target.side, ReviewSide::Right);
 }
 
+#[test]
+fn y_copies_selected_review_comment_with_hunk_and_permalink() {
+    let mut app = App::new(Config::default());
+    app.set_current_repo_with_path("acme", "blippy", None);
+    app.set_current_issue(7, 42);
+    app.set_view(View::PullRequestFiles);
+    app.set_pull_request_files(
+        7,
+        vec![PullRequestFile {
+            filename: "src/main.rs".to_string(),
+            status: "modified".to_string(),
+            additions: 1,
+            deletions: 1,
+            patch: Some("@@ -1,1 +1,2 @@\n-old\n+new\n+more".to_string()),
+        }],
+    );
+    app.set_pull_request_review_comments(vec![PullRequestReviewComment {
+        id: 99,
+        thread_id: None,
+        resolved: false,
+        anchored: true,
+        path: "src/main.rs".to_string(),
+        line: 1,
+        side: ReviewSide::Right,
+        body: "Use `new` here\n\n```rust\nlet x = 1;\n```".to_string(),
+        author: "sam".to_string(),
+        created_at: None,
+    }]);
+    app.set_pull_request_review_focus(PullRequestReviewFocus::Diff);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+    app.on_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+
+    assert_eq!(
+        app.take_action(),
+        Some(AppAction::CopyPullRequestReviewComment)
+    );
+    assert_eq!(
+        app.selected_pull_request_review_comment_quote().as_deref(),
+        Some(
+            "**@sam** on [`src/main.rs:1`](https://github.com/acme/blippy/pull/42#discussion_r99):\n\n\
+             ```diff\n@@ -1,1 +1,2 @@\n-old\n+new\n```\n\n\
+             > Use `new` here\n>\n> ```rust\n> let x = 1;\n> ```"
+        )
+    );
+}
+
 #[test]
 fn review_comment_editor_submit_action_is_emitted() {
     let mut app = App::new(Config::default());
@@ -317,6 +365,67 @@ fn custom_quit_keybinding_remaps_and_disables_default() {
     assert!(app.should_quit());
 }
 
+#[test]
+fn quit_prompts_while_mutations_are_pending() {
+    let mut app = App::new(Config::default());
+    app.set_pending_mutations(2);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    assert!(!app.should_quit());
+    assert!(app.status().contains("2 background changes still running"));
+
+    app.on_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(!app.should_quit());
+    assert!(!app.quit_guard_active());
+
+    app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    assert!(app.should_quit());
+}
+
+#[test]
+fn quit_wait_mode_exits_when_mutations_finish_or_deadline_passes() {
+    let config = Config {
+        quit_guard: Some("wait".to_string()),
+        ..Config::default()
+    };
+    let mut app = App::new(config);
+    let now = Instant::now();
+    app.set_pending_mutations(1);
+
+    app.request_quit_at(now);
+    app.tick_quit_guard_at(now + Duration::from_secs(3));
+    assert!(!app.should_quit());
+    assert!(app.status().contains("(7s)"));
+
+    app.set_pending_mutations(0);
+    app.tick_quit_guard_at(now + Duration::from_secs(4));
+    assert!(app.should_quit());
+
+    let config = Config {
+        quit_guard: Some("wait".to_string()),
+        ..Config::default()
+    };
+    let mut app = App::new(config);
+    app.set_pending_mutations(1);
+    app.request_quit_at(now);
+    app.tick_quit_guard_at(now + Duration::from_secs(10));
+    assert!(app.should_quit());
+}
+
+#[test]
+fn quit_guard_off_exits_immediately() {
+    let config = Config {
+        quit_guard: Some("off".to_string()),
+        ..Config::default()
+    };
+    let mut app = App::new(config);
+    app.set_pending_mutations(3);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    assert!(app.should_quit());
+}
+
 #[test]
 fn shift_n_triggers_create_issue_action() {
     let mut app = App::new(Config::default());
@@ -661,3 +770,20 @@ fn c_collapses_selected_hunk_and_navigation_skips_hidden_rows() {
 
     assert!(!app.pull_request_hunk_is_collapsed("src/main.rs", 0));
 }
+
+#[test]
+fn quit_prompt_is_dismissed_by_other_keys() {
+    let mut app = App::new(Config::default());
+    app.set_view(View::Issues);
+    app.set_pending_mutations(1);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    assert!(app.quit_guard_active());
+
+    app.on_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+    assert!(!app.quit_guard_active());
+
+    app.set_pending_mutations(0);
+    app.tick_quit_guard();
+    assert!(!app.should_quit());
+}
@@ -742,3 +742,48 @@ fn create_issue_confirm_tab_switches_between_cancel_and_create() {
     app.on_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
     assert_eq!(app.take_action(), Some(AppAction::SubmitCreatedIssue));
 }
+
+#[test]
+fn polling_pauses_in_visual_mode_and_refreshes_on_exit() {
+    let mut app = App::new(Config::default());
+    app.set_view(View::PullRequestFiles);
+    app.set_pull_request_files(
+        1,
+        vec![PullRequestFile {
+            filename: "src/main.rs".to_string(),
+            status: "modified".to_string(),
+            additions: 2,
+            deletions: 0,
+            patch: Some("@@ -1,1 +1,3 @@\n old\n+new\n+more".to_string()),
+        }],
+    );
+    app.set_pull_request_review_focus(PullRequestReviewFocus::Diff);
+    assert!(!app.polling_paused());
+
+    app.on_key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT));
+    assert!(app.polling_paused());
+    assert!(!app.take_polling_resumed());
+    assert!(!app.take_sync_request());
+
+    app.on_key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT));
+    assert!(!app.polling_paused());
+    assert!(app.take_polling_resumed());
+    assert!(app.take_sync_request());
+    assert!(app.take_comment_sync_request());
+    assert!(app.take_pull_request_review_comments_sync_request());
+    assert!(!app.take_polling_resumed());
+}
+
+#[test]
+fn polling_resumes_after_comment_editor_closes() {
+    let mut app = App::new(Config::default());
+    app.open_create_issue_editor(View::Issues);
+    assert!(app.polling_paused());
+    assert!(!app.take_polling_resumed());
+
+    app.on_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.view(), View::Issues);
+    assert!(app.take_polling_resumed());
+    assert!(app.take_sync_request());
+    assert!(!app.take_comment_sync_request());
+}
@@ -262,6 +262,53 @@ fn label_picker_type_filter_can_match_c_prefix() {
     assert_eq!(app.selected_label_option(), app.filtered_label_indices()[0]);
 }
 
+#[test]
+fn label_picker_filter_fuzzy_matches_and_ranks_options() {
+    let mut app = App::new(Config::default());
+    app.open_label_picker(
+        View::Issues,
+        vec![
+            "bug".to_string(),
+            "prevent-regression".to_string(),
+            "good first issue".to_string(),
+            "needs-review".to_string(),
+        ],
+        "",
+    );
+
+    for ch in "gfi".chars() {
+        app.on_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+    let filtered = app.filtered_label_indices();
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(app.label_options()[filtered[0]], "good first issue");
+
+    app.on_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    for ch in "rev".chars() {
+        app.on_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+    let filtered = app.filtered_label_indices();
+    assert_eq!(app.label_options()[filtered[0]], "needs-review");
+    assert_eq!(app.selected_label_option(), filtered[0]);
+}
+
+#[test]
+fn label_picker_filter_ignores_query_case() {
+    let mut app = App::new(Config::default());
+    app.open_label_picker(
+        View::Issues,
+        vec!["bug".to_string(), "docs".to_string()],
+        "",
+    );
+
+    for ch in "Bug".chars() {
+        app.on_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+    let filtered = app.filtered_label_indices();
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(app.label_options()[filtered[0]], "bug");
+}
+
 #[test]
 fn merge_label_options_dedupes_case_insensitive() {
     let mut app = App::new(Config::default());
@@ -419,6 +466,32 @@ fn linked_picker_labels_include_cached_titles() {
     assert_eq!(app.linked_picker_labels(), vec!["#22  Fix flaky sync test"]);
 }
 
+#[test]
+fn linked_picker_labels_prefer_fetched_linked_item_titles() {
+    let mut app = App::new(Config::default());
+    app.set_linked_item_summary(33, "Rework auth flow", "merged");
+    app.open_linked_picker(
+        View::IssueDetail,
+        LinkedPickerTarget::PullRequestTui,
+        vec![33],
+    );
+
+    assert_eq!(app.linked_picker_labels(), vec!["#33  Rework auth flow"]);
+    assert_eq!(
+        app.linked_item_summary(33).map(|summary| summary.state),
+        Some("merged".to_string())
+    );
+}
+
+#[test]
+fn switching_repo_clears_linked_item_summaries() {
+    let mut app = App::new(Config::default());
+    app.set_linked_item_summary(33, "Rework auth flow", "open");
+    app.set_current_repo_with_path("acme", "other", None);
+
+    assert_eq!(app.linked_item_summary(33), None);
+}
+
 #[test]
 fn linked_picker_captures_origin_from_selected_pull_request() {
     let mut app = App::new(Config::default());
@@ -524,3 +597,157 @@ fn persistent_status_does_not_clear_from_expiry_check() {
 
     assert_eq!(app.status(), "Sync failed");
 }
+
+#[test]
+fn github_status_checks_are_throttled() {
+    let mut app = App::new(Config::default());
+    let now = std::time::Instant::now();
+
+    app.request_github_status_check_at(now);
+    assert!(app.take_github_status_check_request());
+    app.set_github_status_checking(true);
+    app.request_github_status_check_at(now);
+    assert!(!app.take_github_status_check_request());
+
+    app.set_github_status_notice_at(None, now);
+    app.request_github_status_check_at(now + std::time::Duration::from_secs(30));
+    assert!(!app.take_github_status_check_request());
+    app.request_github_status_check_at(now + std::time::Duration::from_secs(121));
+    assert!(app.take_github_status_check_request());
+}
+
+#[test]
+fn shift_l_opens_link_picker_with_ticket_and_markdown_links() {
+    let mut config = Config::default();
+    config.ticket_links = vec![crate::config::TicketLinkRule {
+        pattern: r"JIRA-\d+".to_string(),
+        url: "https://acme.atlassian.net/browse/$0".to_string(),
+    }];
+    let mut app = App::new(config);
+    app.set_view(View::Issues);
+    app.set_issues(vec![IssueRow {
+        id: 1,
+        repo_id: 1,
+        number: 7,
+        state: "open".to_string(),
+        title: "Sync stalls".to_string(),
+        body: "Tracked in JIRA-12, see [runbook](https://example.com/runbook). `JIRA-99`"
+            .to_string(),
+        labels: String::new(),
+        assignees: String::new(),
+        comments_count: 0,
+        updated_at: None,
+        is_pr: false,
+    }]);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT));
+
+    assert_eq!(app.view(), View::LinkedPicker);
+    assert_eq!(
+        app.linked_picker_target(),
+        Some(LinkedPickerTarget::ExternalLink)
+    );
+    assert_eq!(
+        app.linked_picker_labels(),
+        vec![
+            "JIRA-12  https://acme.atlassian.net/browse/JIRA-12",
+            "runbook  https://example.com/runbook",
+        ]
+    );
+    assert_eq!(
+        app.selected_linked_picker_url(),
+        Some("https://acme.atlassian.net/browse/JIRA-12")
+    );
+}
+
+#[test]
+fn linked_item_summary_prefers_synced_issue_rows_over_cache() {
+    let mut app = App::new(Config::default());
+    app.set_linked_item_summary(33, "Rework auth flow", "open");
+    app.set_issues(vec![IssueRow {
+        id: 3,
+        repo_id: 1,
+        number: 33,
+        state: "merged".to_string(),
+        title: "Rework auth flow (v2)".to_string(),
+        body: String::new(),
+        labels: String::new(),
+        assignees: String::new(),
+        comments_count: 0,
+        updated_at: None,
+        is_pr: true,
+    }]);
+
+    let summary = app.linked_item_summary(33).expect("summary");
+    assert_eq!(summary.state, "merged");
+    assert_eq!(summary.title, "Rework auth flow (v2)");
+}
+
+#[test]
+fn issue_search_ranks_fuzzy_title_matches_after_substring_matches() {
+    let mut app = App::new(Config::default());
+    let issue = |id: i64, number: i64, title: &str| IssueRow {
+        id,
+        repo_id: 1,
+        number,
+        state: "open".to_string(),
+        title: title.to_string(),
+        body: String::new(),
+        labels: String::new(),
+        assignees: String::new(),
+        comments_count: 0,
+        updated_at: None,
+        is_pr: false,
+    };
+    app.set_issues(vec![
+        issue(1, 30, "Add pagination"),
+        issue(2, 10, "API rate limits"),
+        issue(3, 20, "Docs typo"),
+    ]);
+    app.set_view(View::Issues);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+    for ch in "api".chars() {
+        app.on_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+
+    let numbers = app
+        .issues_for_view()
+        .iter()
+        .map(|issue| issue.number)
+        .collect::<Vec<i64>>();
+    assert_eq!(numbers, vec![10, 30]);
+}
+
+#[test]
+fn repo_picker_search_ignores_remote_urls() {
+    let mut app = App::new(Config::default());
+    app.set_repos(vec![
+        LocalRepoRow {
+            path: "/tmp/one".to_string(),
+            remote_name: "origin".to_string(),
+            owner: "acme".to_string(),
+            repo: "blippy".to_string(),
+            url: "https://github.com/acme/blippy.git".to_string(),
+            last_seen: None,
+            last_scanned: None,
+        },
+        LocalRepoRow {
+            path: "/tmp/hub".to_string(),
+            remote_name: "origin".to_string(),
+            owner: "other".to_string(),
+            repo: "core".to_string(),
+            url: "https://github.com/other/core.git".to_string(),
+            last_seen: None,
+            last_scanned: None,
+        },
+    ]);
+
+    app.on_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+    for ch in "hub".chars() {
+        app.on_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+
+    assert_eq!(app.filtered_repo_rows().len(), 1);
+    assert_eq!(app.filtered_repo_rows()[0].repo, "core");
+}
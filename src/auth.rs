@@ -1,4 +1,7 @@
-use std::io;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
 use std::process::Command;
 
 use anyhow::{Context, Result};
@@ -6,6 +9,8 @@ use anyhow::{Context, Result};
 pub trait AuthSources {
     fn gh_token(&self) -> Result<Option<String>>;
     fn keyring_token(&self) -> Result<Option<String>>;
+    fn gh_hosts_credentials(&self) -> Result<Option<GhHostCredentials>>;
+    fn confirm_gh_import(&self, credentials: &GhHostCredentials) -> Result<bool>;
     fn prompt_token(&self) -> Result<String>;
     fn store_token(&self, token: &str) -> Result<()>;
     fn clear_token(&self) -> Result<bool>;
@@ -18,6 +23,7 @@ const DEFAULT_SERVICE: &str = "blippy";
 pub enum AuthMethod {
     Gh,
     Keyring,
+    GhImport,
     Prompt,
 }
 
@@ -26,6 +32,7 @@ impl AuthMethod {
         match self {
             Self::Gh => "gh",
             Self::Keyring => "keyring",
+            Self::GhImport => "gh-import",
             Self::Prompt => "prompt",
         }
     }
@@ -37,6 +44,13 @@ pub struct AuthToken {
     pub method: AuthMethod,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GhHostCredentials {
+    pub host: String,
+    pub user: Option<String>,
+    pub token: String,
+}
+
 pub fn clear_auth_token<S: AuthSources>(sources: &S) -> Result<bool> {
     sources.clear_token()
 }
@@ -58,6 +72,24 @@ pub fn resolve_auth_token<S: AuthSources>(sources: &S) -> Result<AuthToken> {
         });
     }
 
+    // The import is optional, so an unreadable hosts.yml falls through to the prompt.
+    let credentials = match sources.gh_hosts_credentials() {
+        Ok(credentials) => credentials,
+        Err(error) => {
+            eprintln!("Skipping GitHub CLI import: {:#}", error);
+            None
+        }
+    };
+    if let Some(credentials) = credentials
+        && sources.confirm_gh_import(&credentials)?
+    {
+        sources.store_token(&credentials.token)?;
+        return Ok(AuthToken {
+            value: credentials.token,
+            method: AuthMethod::GhImport,
+        });
+    }
+
     let token = sources.prompt_token()?;
     sources.store_token(&token)?;
     Ok(AuthToken {
@@ -115,6 +147,44 @@ impl AuthSources for SystemAuth {
         Ok(normalize_token(&token))
     }
 
+    fn gh_hosts_credentials(&self) -> Result<Option<GhHostCredentials>> {
+        let path = match gh_hosts_path() {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("Failed to read gh config at {}", path.display()));
+            }
+        };
+
+        Ok(parse_gh_hosts(&contents)
+            .into_iter()
+            .find(|credentials| credentials.host == DEFAULT_HOST))
+    }
+
+    fn confirm_gh_import(&self, credentials: &GhHostCredentials) -> Result<bool> {
+        let account = match credentials.user.as_deref() {
+            Some(user) => format!("{} ({})", credentials.host, user),
+            None => credentials.host.clone(),
+        };
+        print!(
+            "Found GitHub CLI credentials for {}. Import into the blippy keychain? [Y/n] ",
+            account
+        );
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer)?;
+        let answer = answer.trim();
+        Ok(answer.is_empty()
+            || answer.eq_ignore_ascii_case("y")
+            || answer.eq_ignore_ascii_case("yes"))
+    }
+
     fn prompt_token(&self) -> Result<String> {
         let prompt = format!(
             "Paste a GitHub Personal Access Token for {}: ",
@@ -144,6 +214,107 @@ impl AuthSources for SystemAuth {
     }
 }
 
+fn gh_hosts_path() -> Option<PathBuf> {
+    if let Ok(dir) = env::var("GH_CONFIG_DIR")
+        && !dir.is_empty()
+    {
+        return Some(PathBuf::from(dir).join("hosts.yml"));
+    }
+
+    if cfg!(windows)
+        && let Ok(dir) = env::var("AppData")
+    {
+        return Some(PathBuf::from(dir).join("GitHub CLI").join("hosts.yml"));
+    }
+
+    if let Ok(dir) = env::var("XDG_CONFIG_HOME")
+        && !dir.is_empty()
+    {
+        return Some(PathBuf::from(dir).join("gh").join("hosts.yml"));
+    }
+
+    env::var("HOME")
+        .ok()
+        .filter(|home| !home.is_empty())
+        .map(|home| {
+            PathBuf::from(home)
+                .join(".config")
+                .join("gh")
+                .join("hosts.yml")
+        })
+}
+
+#[derive(Debug, Default)]
+struct GhHostEntry {
+    host: String,
+    user: Option<String>,
+    token: Option<String>,
+    user_token: Option<String>,
+}
+
+impl GhHostEntry {
+    fn into_credentials(self) -> Option<GhHostCredentials> {
+        let token = self.token.or(self.user_token)?;
+        Some(GhHostCredentials {
+            host: self.host,
+            user: self.user,
+            token,
+        })
+    }
+}
+
+fn parse_gh_hosts(contents: &str) -> Vec<GhHostCredentials> {
+    let mut hosts = Vec::new();
+    let mut current: Option<GhHostEntry> = None;
+    let mut direct_indent = None;
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let (key, value) = match trimmed.split_once(':') {
+            Some((key, value)) => (key.trim(), unquote_yaml_value(value)),
+            None => continue,
+        };
+        let indent = line.len() - line.trim_start().len();
+
+        if indent == 0 {
+            hosts.extend(current.take().and_then(GhHostEntry::into_credentials));
+            current = Some(GhHostEntry {
+                host: key.to_string(),
+                ..GhHostEntry::default()
+            });
+            direct_indent = None;
+            continue;
+        }
+
+        let entry = match current.as_mut() {
+            Some(entry) => entry,
+            None => continue,
+        };
+        let is_direct = *direct_indent.get_or_insert(indent) == indent;
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+        match key {
+            "user" if is_direct => entry.user = Some(value),
+            "oauth_token" if is_direct => entry.token = Some(value),
+            "oauth_token" if entry.user_token.is_none() => entry.user_token = Some(value),
+            _ => {}
+        }
+    }
+
+    hosts.extend(current.take().and_then(GhHostEntry::into_credentials));
+    hosts
+}
+
+fn unquote_yaml_value(raw: &str) -> Option<String> {
+    let value = raw.trim().trim_matches('"').trim_matches('\'');
+    normalize_token(value)
+}
+
 fn normalize_token(raw: &str) -> Option<String> {
     let trimmed = raw.trim();
     if trimmed.is_empty() {
@@ -157,7 +328,7 @@ fn normalize_token(raw: &str) -> Option<String> {
 mod tests {
     use std::cell::RefCell;
 
-    use super::{AuthMethod, AuthSources, resolve_auth_token};
+    use super::{AuthMethod, AuthSources, GhHostCredentials, parse_gh_hosts, resolve_auth_token};
 
     #[test]
     fn resolve_token_prefers_gh_token() {
@@ -188,10 +359,104 @@ mod tests {
 
         assert_eq!(token.value, "prompt-token");
         assert_eq!(token.method, AuthMethod::Prompt);
-        assert_eq!(sources.calls(), vec!["gh", "keyring", "prompt", "store"]);
+        assert_eq!(
+            sources.calls(),
+            vec!["gh", "keyring", "gh_hosts", "prompt", "store"]
+        );
         assert_eq!(sources.stored(), vec!["prompt-token".to_string()]);
     }
 
+    #[test]
+    fn resolve_token_imports_gh_hosts_token_when_confirmed() {
+        let sources = TestSources::new()
+            .with_gh_hosts("hosts-token")
+            .with_import_confirmed(true);
+        let token = resolve_auth_token(&sources).expect("token resolves");
+
+        assert_eq!(token.value, "hosts-token");
+        assert_eq!(token.method, AuthMethod::GhImport);
+        assert_eq!(
+            sources.calls(),
+            vec!["gh", "keyring", "gh_hosts", "confirm_import", "store"]
+        );
+        assert_eq!(sources.stored(), vec!["hosts-token".to_string()]);
+    }
+
+    #[test]
+    fn resolve_token_prompts_when_gh_import_declined() {
+        let sources = TestSources::new()
+            .with_gh_hosts("hosts-token")
+            .with_prompt("prompt-token");
+        let token = resolve_auth_token(&sources).expect("token resolves");
+
+        assert_eq!(token.value, "prompt-token");
+        assert_eq!(token.method, AuthMethod::Prompt);
+        assert_eq!(
+            sources.calls(),
+            vec![
+                "gh",
+                "keyring",
+                "gh_hosts",
+                "confirm_import",
+                "prompt",
+                "store"
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_token_prompts_when_gh_hosts_unreadable() {
+        let sources = TestSources::new()
+            .with_gh_hosts_error()
+            .with_prompt("prompt-token");
+        let token = resolve_auth_token(&sources).expect("token resolves");
+
+        assert_eq!(token.value, "prompt-token");
+        assert_eq!(token.method, AuthMethod::Prompt);
+        assert_eq!(
+            sources.calls(),
+            vec!["gh", "keyring", "gh_hosts", "prompt", "store"]
+        );
+    }
+
+    #[test]
+    fn parse_gh_hosts_reads_host_tokens() {
+        let input = r#"
+github.com:
+    users:
+        octocat:
+            oauth_token: gho_nested
+    git_protocol: https
+    user: octocat
+    oauth_token: "gho_direct"
+ghe.example.com:
+    user: hubot
+    users:
+        hubot:
+            oauth_token: gho_enterprise
+keyring-only.example.com:
+    user: monalisa
+"#;
+
+        let hosts = parse_gh_hosts(input);
+
+        assert_eq!(
+            hosts,
+            vec![
+                GhHostCredentials {
+                    host: "github.com".to_string(),
+                    user: Some("octocat".to_string()),
+                    token: "gho_direct".to_string(),
+                },
+                GhHostCredentials {
+                    host: "ghe.example.com".to_string(),
+                    user: Some("hubot".to_string()),
+                    token: "gho_enterprise".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn normalize_token_trims_and_rejects_empty() {
         assert_eq!(super::normalize_token("  abc\n"), Some("abc".to_string()));
@@ -210,6 +475,9 @@ mod tests {
     struct TestSources {
         gh: Option<String>,
         keyring: Option<String>,
+        gh_hosts: Option<String>,
+        gh_hosts_error: bool,
+        import_confirmed: bool,
         prompt: Option<String>,
         clear_result: bool,
         calls: RefCell<Vec<&'static str>>,
@@ -221,6 +489,9 @@ mod tests {
             Self {
                 gh: None,
                 keyring: None,
+                gh_hosts: None,
+                gh_hosts_error: false,
+                import_confirmed: false,
                 prompt: None,
                 clear_result: false,
                 calls: RefCell::new(Vec::new()),
@@ -238,6 +509,21 @@ mod tests {
             self
         }
 
+        fn with_gh_hosts(mut self, value: &str) -> Self {
+            self.gh_hosts = Some(value.to_string());
+            self
+        }
+
+        fn with_gh_hosts_error(mut self) -> Self {
+            self.gh_hosts_error = true;
+            self
+        }
+
+        fn with_import_confirmed(mut self, value: bool) -> Self {
+            self.import_confirmed = value;
+            self
+        }
+
         fn with_prompt(mut self, value: &str) -> Self {
             self.prompt = Some(value.to_string());
             self
@@ -268,6 +554,23 @@ mod tests {
             Ok(self.keyring.clone())
         }
 
+        fn gh_hosts_credentials(&self) -> anyhow::Result<Option<GhHostCredentials>> {
+            self.calls.borrow_mut().push("gh_hosts");
+            if self.gh_hosts_error {
+                anyhow::bail!("permission denied");
+            }
+            Ok(self.gh_hosts.clone().map(|token| GhHostCredentials {
+                host: "github.com".to_string(),
+                user: Some("octocat".to_string()),
+                token,
+            }))
+        }
+
+        fn confirm_gh_import(&self, _credentials: &GhHostCredentials) -> anyhow::Result<bool> {
+            self.calls.borrow_mut().push("confirm_import");
+            Ok(self.import_confirmed)
+        }
+
         fn prompt_token(&self) -> anyhow::Result<String> {
             self.calls.borrow_mut().push("prompt");
             Ok(self
@@ -10,10 +10,15 @@ use serde::{Deserialize, Serialize};
 pub struct Config {
     pub keymap: Option<String>,
     pub theme: Option<String>,
+    pub quit_guard: Option<String>,
     #[serde(default)]
     pub keybinds: HashMap<String, String>,
     #[serde(default)]
     pub comment_defaults: Vec<CommentDefault>,
+    #[serde(default)]
+    pub ticket_links: Vec<TicketLinkRule>,
+    #[serde(default)]
+    pub repo_colors: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -22,7 +27,46 @@ pub struct CommentDefault {
     pub body: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TicketLinkRule {
+    pub pattern: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuitGuard {
+    #[default]
+    Prompt,
+    Wait,
+    Off,
+}
+
+impl QuitGuard {
+    pub fn from_name(name: Option<&str>) -> Self {
+        let Some(name) = name else {
+            return Self::default();
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "wait" => Self::Wait,
+            "off" | "none" | "instant" => Self::Off,
+            _ => Self::Prompt,
+        }
+    }
+}
+
 impl Config {
+    pub fn quit_guard(&self) -> QuitGuard {
+        QuitGuard::from_name(self.quit_guard.as_deref())
+    }
+
+    pub fn repo_color(&self, owner: &str, repo: &str) -> Option<&str> {
+        let slug = format!("{}/{}", owner, repo);
+        self.repo_colors
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(slug.as_str()))
+            .map(|(_, color)| color.as_str())
+    }
+
     pub fn load() -> Result<Self> {
         let path = config_path();
         let mut config = if !path.exists() {
@@ -43,9 +87,21 @@ impl Config {
             })?;
             config.keybinds.extend(keybinds_file.keybinds);
         }
+        config
+            .validate()
+            .with_context(|| format!("Invalid config at {}", path.display()))?;
         Ok(config)
     }
 
+    fn validate(&self) -> Result<()> {
+        for rule in &self.ticket_links {
+            regex::Regex::new(rule.pattern.as_str()).with_context(|| {
+                format!("Invalid ticket_links pattern {:?}", rule.pattern.as_str())
+            })?;
+        }
+        Ok(())
+    }
+
     pub fn save(&self) -> Result<()> {
         let path = config_path();
         if let Some(parent) = path.parent() {
@@ -62,7 +118,30 @@ impl Config {
 
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use super::{Config, QuitGuard};
+
+    #[test]
+    fn rejects_invalid_ticket_link_patterns() {
+        let input = r#"
+            [[ticket_links]]
+            pattern = "JIRA-(\\d+"
+            url = "https://acme.atlassian.net/browse/JIRA-$1"
+        "#;
+
+        let config: Config = toml::from_str(input).expect("parse config");
+        let error = config.validate().expect_err("invalid pattern");
+        assert!(format!("{:#}", error).contains("Invalid ticket_links pattern"));
+
+        let valid: Config = toml::from_str(
+            r#"
+            [[ticket_links]]
+            pattern = "JIRA-(\\d+)"
+            url = "https://acme.atlassian.net/browse/JIRA-$1"
+        "#,
+        )
+        .expect("parse config");
+        assert!(valid.validate().is_ok());
+    }
 
     #[test]
     fn parses_comment_defaults() {
@@ -99,6 +178,44 @@ mod tests {
         let config: Config = toml::from_str(input).expect("parse config");
         assert_eq!(config.theme.as_deref(), Some("midnight"));
     }
+
+    #[test]
+    fn parses_repo_colors_case_insensitive() {
+        let input = r##"
+            [repo_colors]
+            "AksharP5/blippy" = "#ff8800"
+        "##;
+
+        let config: Config = toml::from_str(input).expect("parse config");
+        assert_eq!(config.repo_color("aksharp5", "BLIPPY"), Some("#ff8800"));
+        assert_eq!(config.repo_color("acme", "api"), None);
+    }
+
+    #[test]
+    fn parses_ticket_link_rules() {
+        let input = r#"
+            [[ticket_links]]
+            pattern = 'JIRA-\d+'
+            url = "https://acme.atlassian.net/browse/$0"
+        "#;
+
+        let config: Config = toml::from_str(input).expect("parse config");
+        assert_eq!(config.ticket_links.len(), 1);
+        assert_eq!(config.ticket_links[0].pattern, r"JIRA-\d+");
+    }
+
+    #[test]
+    fn parses_quit_guard_mode() {
+        let input = r#"
+            quit_guard = "Wait"
+        "#;
+
+        let config: Config = toml::from_str(input).expect("parse config");
+        assert_eq!(config.quit_guard(), QuitGuard::Wait);
+        assert_eq!(Config::default().quit_guard(), QuitGuard::Prompt);
+        assert_eq!(QuitGuard::from_name(Some("off")), QuitGuard::Off);
+        assert_eq!(QuitGuard::from_name(Some("unknown")), QuitGuard::Prompt);
+    }
 }
 
 #[derive(Debug, Default, Deserialize)]
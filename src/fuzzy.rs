@@ -0,0 +1,160 @@
+const SCORE_MATCH: i64 = 16;
+const BONUS_BOUNDARY: i64 = 8;
+const BONUS_CAMEL: i64 = 7;
+const BONUS_CONSECUTIVE: i64 = 4;
+const BONUS_FIRST_CHAR_MULTIPLIER: i64 = 2;
+const PENALTY_GAP_START: i64 = 3;
+const PENALTY_GAP_EXTENSION: i64 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    pub positions: Vec<usize>,
+}
+
+/// Matches every whitespace-separated term of `query` as a subsequence of
+/// `candidate`, ignoring case. Positions are char indices into `candidate`.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let candidate = candidate.chars().collect::<Vec<char>>();
+    let mut score = 0;
+    let mut positions = Vec::new();
+    for term in query.split_whitespace() {
+        let matched = match_term(term, candidate.as_slice())?;
+        score += matched.score;
+        positions.extend(matched.positions);
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Filters `candidates` by `query`, best matches first. Ties keep their
+/// original order, and an empty query keeps every candidate.
+pub fn rank<'a, I>(query: &str, candidates: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let candidates = candidates.into_iter();
+    if query.trim().is_empty() {
+        return candidates.enumerate().map(|(index, _)| index).collect();
+    }
+    let mut scored = candidates
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            fuzzy_match(query, candidate).map(|matched| (index, matched.score))
+        })
+        .collect::<Vec<(usize, i64)>>();
+    scored.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(index, _)| index).collect()
+}
+
+fn match_term(term: &str, candidate: &[char]) -> Option<FuzzyMatch> {
+    let fold = |ch: char| ch.to_lowercase().next().unwrap_or(ch);
+    let term = term.chars().map(fold).collect::<Vec<char>>();
+    if term.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let mut term_index = 0;
+    let mut end = None;
+    for (index, ch) in candidate.iter().enumerate() {
+        if fold(*ch) == term[term_index] {
+            term_index += 1;
+            if term_index == term.len() {
+                end = Some(index);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    // Walk back from the end of the first match to find the tightest window.
+    let mut positions = vec![0; term.len()];
+    let mut term_index = term.len();
+    for index in (0..=end).rev() {
+        if fold(candidate[index]) == term[term_index - 1] {
+            term_index -= 1;
+            positions[term_index] = index;
+            if term_index == 0 {
+                break;
+            }
+        }
+    }
+
+    Some(FuzzyMatch {
+        score: score_positions(candidate, positions.as_slice()),
+        positions,
+    })
+}
+
+fn score_positions(candidate: &[char], positions: &[usize]) -> i64 {
+    let mut score = 0;
+    let mut previous: Option<usize> = None;
+    for (term_index, position) in positions.iter().copied().enumerate() {
+        let mut bonus = position_bonus(candidate, position);
+        if term_index == 0 {
+            bonus *= BONUS_FIRST_CHAR_MULTIPLIER;
+        }
+        if let Some(previous) = previous {
+            let gap = position - previous - 1;
+            if gap == 0 {
+                bonus = bonus.max(BONUS_CONSECUTIVE);
+            } else {
+                score -= PENALTY_GAP_START + PENALTY_GAP_EXTENSION * (gap as i64 - 1);
+            }
+        }
+        score += SCORE_MATCH + bonus;
+        previous = Some(position);
+    }
+    score
+}
+
+fn position_bonus(candidate: &[char], position: usize) -> i64 {
+    let current = candidate[position];
+    let Some(previous) = position.checked_sub(1).map(|index| candidate[index]) else {
+        return BONUS_BOUNDARY;
+    };
+    if !previous.is_alphanumeric() && current.is_alphanumeric() {
+        return BONUS_BOUNDARY;
+    }
+    if previous.is_lowercase() && current.is_uppercase() {
+        return BONUS_CAMEL;
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzy_match, rank};
+
+    #[test]
+    fn matches_subsequence_and_reports_positions() {
+        let matched = fuzzy_match("gdk", "good-first-kit").expect("match");
+        assert_eq!(matched.positions, vec![0, 3, 11]);
+        assert!(fuzzy_match("xyz", "good-first-kit").is_none());
+    }
+
+    #[test]
+    fn folds_unicode_case_in_query_and_candidate() {
+        assert!(fuzzy_match("émi", "Émile").is_some());
+        assert!(fuzzy_match("Emi", "emile").is_some());
+        assert!(fuzzy_match("Bug", "bug").is_some());
+    }
+
+    #[test]
+    fn requires_every_term_to_match() {
+        let matched = fuzzy_match("bug ui", "bug: ui glitch").expect("match");
+        assert_eq!(matched.positions, vec![0, 1, 2, 5, 6]);
+        assert!(fuzzy_match("bug api", "bug: ui glitch").is_none());
+    }
+
+    #[test]
+    fn ranks_tight_and_boundary_matches_first() {
+        let candidates = ["prevent", "needs-review", "bug"];
+        assert_eq!(rank("rev", candidates), vec![1, 0]);
+        assert_eq!(rank("", candidates), vec![0, 1, 2]);
+    }
+}
@@ -71,7 +71,7 @@ impl GitHubClient {
         owner: &str,
         repo: &str,
         issue_number: i64,
-    ) -> Result<Vec<(i64, String)>> {
+    ) -> Result<Vec<ApiLinkedItem>> {
         let mut linked = Vec::new();
         let mut seen = HashSet::new();
         let mut page = 1u32;
@@ -112,7 +112,11 @@ impl GitHubClient {
                 if !html_url.contains("/pull/") || !seen.insert(pull_number) {
                     continue;
                 }
-                linked.push((pull_number, html_url.to_string()));
+                linked.push(linked_item_from_timeline_issue(
+                    issue,
+                    pull_number,
+                    html_url,
+                ));
             }
 
             if events.len() < 100 {
@@ -129,7 +133,7 @@ impl GitHubClient {
         owner: &str,
         repo: &str,
         pull_number: i64,
-    ) -> Result<Vec<(i64, String)>> {
+    ) -> Result<Vec<ApiLinkedItem>> {
         let mut linked = Vec::new();
         let mut seen = HashSet::new();
         let mut page = 1u32;
@@ -170,7 +174,11 @@ impl GitHubClient {
                 if !html_url.contains("/issues/") || !seen.insert(issue_number) {
                     continue;
                 }
-                linked.push((issue_number, html_url.to_string()));
+                linked.push(linked_item_from_timeline_issue(
+                    issue,
+                    issue_number,
+                    html_url,
+                ));
             }
 
             if events.len() < 100 {
@@ -304,3 +312,28 @@ impl GitHubClient {
         Ok(assignees)
     }
 }
+
+fn linked_item_from_timeline_issue(
+    issue: &serde_json::Value,
+    number: i64,
+    html_url: &str,
+) -> ApiLinkedItem {
+    let title = issue
+        .get("title")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let state = work_item_state(
+        issue
+            .get("state")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default(),
+        issue.get("pull_request"),
+    );
+    ApiLinkedItem {
+        number,
+        html_url: html_url.to_string(),
+        title,
+        state,
+    }
+}
@@ -5,8 +5,10 @@ mod comments;
 mod issues;
 mod pull_requests;
 mod repos;
+mod status;
 mod types;
 
+pub use status::service_status_notice;
 pub use types::*;
 
 const API_BASE: &str = "https://api.github.com";
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use super::{ApiStatusComponent, GitHubClient};
+
+const STATUS_COMPONENTS_URL: &str = "https://www.githubstatus.com/api/v2/components.json";
+const WATCHED_COMPONENTS: &[&str] = &["API Requests", "Issues", "Pull Requests"];
+
+#[derive(Debug, Deserialize)]
+struct StatusComponentsResponse {
+    #[serde(default)]
+    components: Vec<ApiStatusComponent>,
+}
+
+impl GitHubClient {
+    pub async fn service_status(&self) -> Result<Vec<ApiStatusComponent>> {
+        let response = self
+            .client
+            .get(STATUS_COMPONENTS_URL)
+            .send()
+            .await?
+            .error_for_status()?;
+        let payload = response.json::<StatusComponentsResponse>().await?;
+        Ok(payload.components)
+    }
+}
+
+pub fn service_status_notice(components: &[ApiStatusComponent]) -> Option<String> {
+    let reports = WATCHED_COMPONENTS
+        .iter()
+        .filter_map(|watched| {
+            let component = components
+                .iter()
+                .find(|component| component.name.eq_ignore_ascii_case(watched))?;
+            component_report(component)
+        })
+        .collect::<Vec<String>>();
+    if reports.is_empty() {
+        return None;
+    }
+    Some(format!("GitHub is reporting {}", reports.join(", ")))
+}
+
+fn component_report(component: &ApiStatusComponent) -> Option<String> {
+    let name = match component.name.as_str() {
+        "API Requests" => "API".to_string(),
+        name => format!("{} API", name),
+    };
+    match component.status.as_str() {
+        "degraded_performance" => Some(format!("degraded {} performance", name)),
+        "partial_outage" => Some(format!("a partial {} outage", name)),
+        "major_outage" => Some(format!("a major {} outage", name)),
+        "under_maintenance" => Some(format!("{} maintenance", name)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::service_status_notice;
+    use crate::github::ApiStatusComponent;
+
+    fn component(name: &str, status: &str) -> ApiStatusComponent {
+        ApiStatusComponent {
+            name: name.to_string(),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn notice_describes_degraded_watched_components() {
+        let components = vec![
+            component("Git Operations", "major_outage"),
+            component("API Requests", "operational"),
+            component("Issues", "degraded_performance"),
+            component("Pull Requests", "partial_outage"),
+        ];
+
+        assert_eq!(
+            service_status_notice(&components).as_deref(),
+            Some(
+                "GitHub is reporting degraded Issues API performance, a partial Pull Requests API outage"
+            )
+        );
+    }
+
+    #[test]
+    fn notice_is_empty_when_watched_components_are_operational() {
+        let components = vec![
+            component("Actions", "major_outage"),
+            component("Issues", "operational"),
+        ];
+
+        assert_eq!(service_status_notice(&components), None);
+    }
+}
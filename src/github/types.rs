@@ -46,6 +46,19 @@ pub struct ApiIssue {
     pub pull_request: Option<serde_json::Value>,
 }
 
+/// The issue `state` reported by the API, or `merged` when the attached
+/// `pull_request` object carries a `merged_at` timestamp.
+pub fn work_item_state(state: &str, pull_request: Option<&serde_json::Value>) -> String {
+    let is_merged = pull_request
+        .and_then(|pull_request| pull_request.get("merged_at"))
+        .and_then(serde_json::Value::as_str)
+        .is_some();
+    if is_merged {
+        return "merged".to_string();
+    }
+    state.to_string()
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ApiComment {
     pub id: i64,
@@ -117,6 +130,14 @@ pub struct ApiPullRequestReviewComment {
     pub user: ApiUser,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiLinkedItem {
+    pub number: i64,
+    pub html_url: String,
+    pub title: String,
+    pub state: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct ApiIssuesPage {
     pub issues: Vec<ApiIssue>,
@@ -128,3 +149,9 @@ pub enum ApiIssuesPageResult {
     NotModified,
     Page(ApiIssuesPage),
 }
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiStatusComponent {
+    pub name: String,
+    pub status: String,
+}
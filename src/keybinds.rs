@@ -195,11 +195,26 @@ pub const BINDING_SPECS: &[BindingSpec] = &[
         default: "shift+p",
         description: "Open linked PR in TUI",
     },
+    BindingSpec {
+        action: "open_links",
+        default: "shift+l",
+        description: "Pick a link in the current item to open in browser",
+    },
     BindingSpec {
         action: "checkout_pr",
         default: "v",
         description: "Checkout selected pull request",
     },
+    BindingSpec {
+        action: "copy_issue_checklist",
+        default: "y",
+        description: "Copy filtered list as Markdown checklist",
+    },
+    BindingSpec {
+        action: "copy_review_comment",
+        default: "y",
+        description: "Copy review comment, hunk, and permalink as Markdown",
+    },
     BindingSpec {
         action: "merge_pull_request",
         default: "shift+m",
@@ -3,6 +3,7 @@ mod auth;
 mod cli;
 mod config;
 mod discovery;
+mod fuzzy;
 mod git;
 mod github;
 mod keybinds;
@@ -25,6 +26,7 @@ mod main_sync;
 use std::collections::{HashMap, HashSet};
 use std::env;
 use std::io::{self, Stdout};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -47,12 +49,12 @@ use crate::cli::{CliCommand, parse_args};
 use crate::config::Config;
 use crate::discovery::{home_dir, quick_scan};
 use crate::git::list_github_remotes_at;
-use crate::github::GitHubClient;
+use crate::github::{ApiLinkedItem, GitHubClient, service_status_notice};
 use crate::repo_index::index_repo_path;
 use crate::store::delete_db;
 use crate::store::{
-    comment_now_epoch, comments_for_issue, get_repo_by_slug, list_issues, list_local_repos,
-    prune_comments, touch_comments_for_issue, update_issue_comments_count,
+    comment_now_epoch, comments_for_issue, get_repo_by_slug, list_issues, list_linked_items,
+    list_local_repos, prune_comments, touch_comments_for_issue, update_issue_comments_count,
 };
 use crate::sync::{SyncStats, sync_repo_with_progress};
 
@@ -128,6 +130,48 @@ where
     });
 }
 
+static PENDING_MUTATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct PendingMutationGuard;
+
+impl PendingMutationGuard {
+    fn start() -> Self {
+        PENDING_MUTATIONS.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for PendingMutationGuard {
+    fn drop(&mut self) {
+        PENDING_MUTATIONS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn pending_mutations() -> usize {
+    PENDING_MUTATIONS.load(Ordering::SeqCst)
+}
+
+fn spawn_mutation_with_services<F, E>(
+    token: String,
+    event_tx: Sender<AppEvent>,
+    on_setup_error: E,
+    work: F,
+) where
+    F: FnOnce(WorkerServices, Sender<AppEvent>) + Send + 'static,
+    E: FnOnce(String) -> AppEvent + Send + 'static,
+{
+    let guard = PendingMutationGuard::start();
+    spawn_with_services(
+        token,
+        event_tx,
+        on_setup_error,
+        move |services, event_tx| {
+            let _guard = guard;
+            work(services, event_tx);
+        },
+    );
+}
+
 fn spawn_with_db<F, E>(token: String, event_tx: Sender<AppEvent>, on_setup_error: E, work: F)
 where
     F: FnOnce(WorkerContext, Sender<AppEvent>) + Send + 'static,
@@ -292,6 +336,8 @@ fn run_app(
             &mut last_issue_poll,
             &mut last_comment_poll,
         )?;
+        app.set_pending_mutations(pending_mutations());
+        app.tick_quit_guard();
         app.clear_status_if_expired();
         terminal.draw(|frame| ui::draw(frame, app))?;
 
@@ -337,6 +383,7 @@ fn drive_background_tasks(
     last_issue_poll: &mut Instant,
     last_comment_poll: &mut Instant,
 ) -> Result<()> {
+    main_sync::maybe_resume_polling(app, last_issue_poll, last_comment_poll);
     main_sync::maybe_start_issue_poll(app, last_issue_poll);
     main_sync::maybe_start_repo_sync(app, token, event_tx.clone())?;
     main_sync::maybe_start_repo_permissions_sync(app, token, event_tx.clone());
@@ -345,6 +392,7 @@ fn drive_background_tasks(
     main_sync::maybe_start_pull_request_files_sync(app, token, event_tx.clone())?;
     main_sync::maybe_start_pull_request_review_comments_sync(app, token, event_tx.clone())?;
     main_linked_actions::maybe_probe_visible_linked_items(app, token, event_tx.clone());
+    main_sync::maybe_start_github_status_check(app, token, event_tx.clone());
     if app.view() == View::RepoPicker && app.repos().is_empty() {
         app.set_repos(main_data::load_repos(conn)?);
     }
@@ -477,7 +525,7 @@ enum AppEvent {
     },
     LinkedPullRequestResolved {
         issue_number: i64,
-        pull_requests: Vec<(i64, String)>,
+        pull_requests: Vec<ApiLinkedItem>,
         target: LinkedPullRequestTarget,
     },
     LinkedPullRequestLookupFailed {
@@ -487,7 +535,7 @@ enum AppEvent {
     },
     LinkedIssueResolved {
         pull_number: i64,
-        issues: Vec<(i64, String)>,
+        issues: Vec<ApiLinkedItem>,
         target: LinkedIssueTarget,
     },
     LinkedIssueLookupFailed {
@@ -498,6 +546,7 @@ enum AppEvent {
     IssueUpdated {
         issue_number: i64,
         message: String,
+        failed: bool,
     },
     IssueCreated {
         issue_number: i64,
@@ -544,6 +593,31 @@ enum AppEvent {
         repo: String,
         message: String,
     },
+    GitHubStatusChecked {
+        notice: Option<String>,
+    },
+}
+
+impl AppEvent {
+    fn is_api_failure(&self) -> bool {
+        match self {
+            AppEvent::SyncFailed { .. }
+            | AppEvent::CommentsFailed { .. }
+            | AppEvent::PullRequestFilesFailed { .. }
+            | AppEvent::PullRequestReviewCommentsFailed { .. }
+            | AppEvent::PullRequestReviewCommentCreateFailed { .. }
+            | AppEvent::PullRequestReviewCommentUpdateFailed { .. }
+            | AppEvent::PullRequestReviewCommentDeleteFailed { .. }
+            | AppEvent::PullRequestReviewThreadResolutionFailed { .. }
+            | AppEvent::PullRequestFileViewedUpdateFailed { .. }
+            | AppEvent::LinkedPullRequestLookupFailed { .. }
+            | AppEvent::LinkedIssueLookupFailed { .. }
+            | AppEvent::IssueCreateFailed { .. }
+            | AppEvent::RepoPermissionsFailed { .. } => true,
+            AppEvent::IssueUpdated { failed, .. } => *failed,
+            _ => false,
+        }
+    }
 }
 
 fn refresh_current_repo_issues(app: &mut App, conn: &rusqlite::Connection) -> Result<()> {
@@ -287,6 +287,7 @@ fn issue_updated_marks_pull_request_merged() {
         .send(super::AppEvent::IssueUpdated {
             issue_number: 92,
             message: "merged".to_string(),
+            failed: false,
         })
         .expect("send event");
     super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
@@ -317,3 +318,111 @@ fn submit_created_issue_requires_non_empty_title() {
     assert_eq!(app.status(), "Issue title required");
     assert_eq!(app.view(), View::CommentEditor);
 }
+
+#[test]
+fn api_failures_request_github_status_check_and_surface_notice() {
+    let conn = rusqlite::Connection::open_in_memory().expect("conn");
+    let mut app = crate::app::App::new(Config::default());
+    app.set_current_repo_with_path("acme", "blippy", None);
+
+    let (event_tx, event_rx) = channel();
+    event_tx
+        .send(super::AppEvent::SyncFailed {
+            owner: "acme".to_string(),
+            repo: "blippy".to_string(),
+            message: "error sending request".to_string(),
+        })
+        .expect("send event");
+    super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
+    assert!(app.take_github_status_check_request());
+
+    event_tx
+        .send(super::AppEvent::GitHubStatusChecked {
+            notice: Some("GitHub is reporting degraded Issues API performance".to_string()),
+        })
+        .expect("send event");
+    super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
+    assert_eq!(
+        app.github_status_notice(),
+        Some("GitHub is reporting degraded Issues API performance")
+    );
+}
+
+#[test]
+fn linked_items_from_other_repos_are_not_cached_under_current_repo() {
+    let conn = crate::store::open_db_at(std::path::Path::new(":memory:")).expect("conn");
+    let mut app = crate::app::App::new(Config::default());
+    app.set_current_repo_with_path("acme", "blippy", None);
+
+    let (event_tx, event_rx) = channel();
+    event_tx
+        .send(super::AppEvent::LinkedPullRequestResolved {
+            issue_number: 7,
+            pull_requests: vec![
+                crate::github::ApiLinkedItem {
+                    number: 12,
+                    html_url: "https://github.com/other/fork/pull/12".to_string(),
+                    title: "Foreign fix".to_string(),
+                    state: "open".to_string(),
+                },
+                crate::github::ApiLinkedItem {
+                    number: 13,
+                    html_url: "https://github.com/Acme/Blippy/pull/13".to_string(),
+                    title: "Local fix".to_string(),
+                    state: "merged".to_string(),
+                },
+            ],
+            target: super::LinkedPullRequestTarget::Probe,
+        })
+        .expect("send event");
+    super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
+
+    assert!(app.linked_item_summary(12).is_none());
+    assert_eq!(
+        app.linked_item_summary(13).map(|summary| summary.title),
+        Some("Local fix".to_string())
+    );
+    let cached = crate::store::list_linked_items(&conn, "acme", "blippy").expect("list");
+    assert_eq!(
+        cached.iter().map(|item| item.number).collect::<Vec<i64>>(),
+        vec![13]
+    );
+}
+
+#[test]
+fn issue_updates_request_status_check_only_when_flagged_failed() {
+    let conn = rusqlite::Connection::open_in_memory().expect("conn");
+    let mut app = crate::app::App::new(Config::default());
+    app.set_current_repo_with_path("acme", "blippy", None);
+
+    let (event_tx, event_rx) = channel();
+    event_tx
+        .send(super::AppEvent::IssueUpdated {
+            issue_number: 5,
+            message: "closed".to_string(),
+            failed: false,
+        })
+        .expect("send event");
+    super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
+    assert!(!app.take_github_status_check_request());
+
+    event_tx
+        .send(super::AppEvent::IssueUpdated {
+            issue_number: 5,
+            message: "closed (comment failed: timeout)".to_string(),
+            failed: true,
+        })
+        .expect("send event");
+    super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
+    assert!(app.take_github_status_check_request());
+
+    event_tx
+        .send(super::AppEvent::IssueUpdated {
+            issue_number: 5,
+            message: "reopen failed: timeout".to_string(),
+            failed: true,
+        })
+        .expect("send event");
+    super::main_events::handle_events(&mut app, &conn, &event_rx).expect("handle events");
+    assert!(app.take_github_status_check_request());
+}
@@ -0,0 +1,100 @@
+use super::*;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+pub(crate) fn copy_issue_checklist(app: &mut App) -> Result<()> {
+    let count = app.issues_for_view().len();
+    if count == 0 {
+        app.set_status(format!("No {} to copy", app.work_item_mode().label()));
+        return Ok(());
+    }
+
+    let checklist = app.issue_checklist_markdown();
+    if let Err(error) = copy_to_clipboard(checklist.as_str()) {
+        app.set_status(format!("Copy failed: {}", error));
+        return Ok(());
+    }
+    app.set_transient_status(
+        format!("Copied {} items as checklist", count),
+        Duration::from_secs(2),
+    );
+    Ok(())
+}
+
+pub(crate) fn copy_pull_request_review_comment(app: &mut App) -> Result<()> {
+    let quote = match app.selected_pull_request_review_comment_quote() {
+        Some(quote) => quote,
+        None => {
+            app.set_status("No review comment selected".to_string());
+            return Ok(());
+        }
+    };
+
+    if let Err(error) = copy_to_clipboard(quote.as_str()) {
+        app.set_status(format!("Copy failed: {}", error));
+        return Ok(());
+    }
+    app.set_transient_status(
+        "Copied review comment with permalink",
+        Duration::from_secs(2),
+    );
+    Ok(())
+}
+
+pub(crate) fn copy_to_clipboard(text: &str) -> Result<()> {
+    let payload = clipboard_payload(text);
+    let mut last_error = None;
+    for (program, args) in clipboard_commands() {
+        match pipe_to_command(Command::new(program).args(*args), payload.as_slice()) {
+            Ok(()) => return Ok(()),
+            Err(error) => last_error = Some(error),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| anyhow::anyhow!("no clipboard command available")))
+}
+
+/// `clip` reads stdin in the console code page unless it starts with a
+/// UTF-16LE byte order mark, so Windows gets UTF-16 to keep non-ASCII intact.
+fn clipboard_payload(text: &str) -> Vec<u8> {
+    if !cfg!(target_os = "windows") {
+        return text.as_bytes().to_vec();
+    }
+
+    let mut payload = vec![0xFF, 0xFE];
+    for unit in text.encode_utf16() {
+        payload.extend_from_slice(&unit.to_le_bytes());
+    }
+    payload
+}
+
+fn clipboard_commands() -> &'static [(&'static str, &'static [&'static str])] {
+    if cfg!(target_os = "macos") {
+        return &[("pbcopy", &[])];
+    }
+
+    if cfg!(target_os = "windows") {
+        return &[("clip", &[])];
+    }
+
+    &[
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ]
+}
+
+fn pipe_to_command(command: &mut Command, payload: &[u8]) -> Result<()> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(payload)?;
+    }
+    let status = child.wait()?;
+    if status.success() {
+        return Ok(());
+    }
+    anyhow::bail!("command exited with status {}", status)
+}
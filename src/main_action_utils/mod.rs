@@ -1,12 +1,14 @@
 use super::*;
 
 mod checkout;
+mod clipboard;
 mod issue_actions;
 mod issue_selection;
 mod pr_review_actions;
 mod preset;
 
 pub(super) use checkout::checkout_pull_request;
+pub(super) use clipboard::{copy_issue_checklist, copy_pull_request_review_comment};
 pub(super) use issue_actions::{
     close_issue_with_comment, create_issue, delete_issue_comment, merge_pull_request,
     post_issue_comment, reopen_issue, submit_created_issue, update_issue_assignees,
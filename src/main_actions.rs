@@ -92,6 +92,12 @@ pub(super) fn handle_actions(
         AppAction::CheckoutPullRequest => {
             checkout_pull_request(app)?;
         }
+        AppAction::CopyIssueChecklist => {
+            copy_issue_checklist(app)?;
+        }
+        AppAction::CopyPullRequestReviewComment => {
+            copy_pull_request_review_comment(app)?;
+        }
         AppAction::MergePullRequest => {
             merge_pull_request(app, token, event_tx.clone())?;
         }
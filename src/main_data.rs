@@ -44,6 +44,9 @@ pub(super) fn load_issues_for_slug(
     repo_path: Option<&str>,
 ) -> Result<()> {
     app.set_current_repo_with_path(owner, repo, repo_path);
+    for item in list_linked_items(conn, owner, repo)? {
+        app.set_linked_item_summary(item.number, item.title.as_str(), item.state.as_str());
+    }
     let repo_row = get_repo_by_slug(conn, owner, repo)?;
     let repo_row = match repo_row {
         Some(repo_row) => repo_row,
@@ -6,6 +6,9 @@ pub(super) fn handle_events(
     event_rx: &Receiver<AppEvent>,
 ) -> Result<()> {
     while let Ok(event) = event_rx.try_recv() {
+        if event.is_api_failure() {
+            app.request_github_status_check();
+        }
         match event {
             AppEvent::ReposUpdated => {
                 if app.view() == View::RepoPicker {
@@ -21,6 +24,7 @@ pub(super) fn handle_events(
             }
             AppEvent::SyncFinished { owner, repo, stats } => {
                 app.set_syncing(false);
+                app.clear_github_status_notice();
                 if app.current_owner() == Some(owner.as_str())
                     && app.current_repo() == Some(repo.as_str())
                 {
@@ -85,6 +89,7 @@ pub(super) fn handle_events(
             AppEvent::IssueUpdated {
                 issue_number,
                 message,
+                ..
             } => {
                 if message.starts_with("closed")
                     || message.starts_with("close failed")
@@ -273,9 +278,10 @@ pub(super) fn handle_events(
             } => {
                 let pull_numbers = pull_requests
                     .iter()
-                    .map(|(pull_number, _url)| *pull_number)
+                    .map(|pull_request| pull_request.number)
                     .collect::<Vec<i64>>();
                 app.set_linked_pull_requests(issue_number, pull_numbers.clone());
+                cache_linked_items(app, conn, &pull_requests)?;
 
                 if pull_numbers.is_empty() {
                     if target == LinkedPullRequestTarget::Probe {
@@ -308,9 +314,9 @@ pub(super) fn handle_events(
                 }
 
                 let pull_number = pull_numbers[0];
-                let url = pull_requests.into_iter().find_map(|(number, url)| {
-                    if number == pull_number {
-                        Some(url)
+                let url = pull_requests.into_iter().find_map(|pull_request| {
+                    if pull_request.number == pull_number {
+                        Some(pull_request.html_url)
                     } else {
                         None
                     }
@@ -393,9 +399,10 @@ pub(super) fn handle_events(
             } => {
                 let issue_numbers = issues
                     .iter()
-                    .map(|(issue_number, _url)| *issue_number)
+                    .map(|issue| issue.number)
                     .collect::<Vec<i64>>();
                 app.set_linked_issues_for_pull_request(pull_number, issue_numbers.clone());
+                cache_linked_items(app, conn, &issues)?;
 
                 if issue_numbers.is_empty() {
                     if target == LinkedIssueTarget::Probe {
@@ -425,9 +432,9 @@ pub(super) fn handle_events(
                 }
 
                 let issue_number = issue_numbers[0];
-                let url = issues.into_iter().find_map(|(number, url)| {
-                    if number == issue_number {
-                        Some(url)
+                let url = issues.into_iter().find_map(|issue| {
+                    if issue.number == issue_number {
+                        Some(issue.html_url)
                     } else {
                         None
                     }
@@ -582,7 +589,51 @@ pub(super) fn handle_events(
                     app.set_status(format!("Repo permission check failed: {}", message));
                 }
             }
+            AppEvent::GitHubStatusChecked { notice } => {
+                app.set_github_status_notice(notice);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cache_linked_items(
+    app: &mut App,
+    conn: &rusqlite::Connection,
+    items: &[ApiLinkedItem],
+) -> Result<()> {
+    let (owner, repo) = match (app.current_owner(), app.current_repo()) {
+        (Some(owner), Some(repo)) => (owner.to_string(), repo.to_string()),
+        _ => return Ok(()),
+    };
+    for item in items {
+        if !linked_item_in_repo(item.html_url.as_str(), owner.as_str(), repo.as_str()) {
+            continue;
         }
+        app.set_linked_item_summary(item.number, item.title.as_str(), item.state.as_str());
+        crate::store::upsert_linked_item(
+            conn,
+            &crate::store::LinkedItemRow {
+                owner: owner.clone(),
+                repo: repo.clone(),
+                number: item.number,
+                title: item.title.clone(),
+                state: item.state.clone(),
+            },
+        )?;
     }
     Ok(())
 }
+
+fn linked_item_in_repo(html_url: &str, owner: &str, repo: &str) -> bool {
+    let path = html_url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let mut segments = path.split('/').skip(1);
+    match (segments.next(), segments.next()) {
+        (Some(item_owner), Some(item_repo)) => {
+            item_owner.eq_ignore_ascii_case(owner) && item_repo.eq_ignore_ascii_case(repo)
+        }
+        _ => false,
+    }
+}
@@ -277,6 +277,9 @@ pub(super) fn open_selected_linked_item(app: &mut App, conn: &rusqlite::Connecti
             return Ok(());
         }
     };
+    if target == LinkedPickerTarget::ExternalLink {
+        return open_selected_external_link(app);
+    }
     let number = match app.selected_linked_picker_number() {
         Some(number) => number,
         None => {
@@ -348,6 +351,7 @@ pub(super) fn open_selected_linked_item(app: &mut App, conn: &rusqlite::Connecti
             }
             app.set_status(format!("Opened linked issue #{} in browser", number));
         }
+        LinkedPickerTarget::ExternalLink => app.set_view(cancel_view),
     }
 
     Ok(())
@@ -505,6 +509,25 @@ pub(super) fn start_linked_issue_lookup(
     );
 }
 
+fn open_selected_external_link(app: &mut App) -> Result<()> {
+    let url = match app.selected_linked_picker_url() {
+        Some(url) => url.to_string(),
+        None => {
+            app.set_status("No link selected".to_string());
+            return Ok(());
+        }
+    };
+    let cancel_view = app.linked_picker_cancel_view();
+    app.clear_linked_picker_state();
+    app.set_view(cancel_view);
+    if let Err(error) = open_external_url(url.as_str()) {
+        app.set_status(format!("Open link failed: {}", error));
+        return Ok(());
+    }
+    app.set_transient_status(format!("Opened {}", url), Duration::from_secs(2));
+    Ok(())
+}
+
 pub(super) fn open_url(url: &str) -> Result<()> {
     if cfg!(target_os = "macos") {
         return run_silent_command(std::process::Command::new("open").arg(url));
@@ -517,6 +540,17 @@ pub(super) fn open_url(url: &str) -> Result<()> {
     run_silent_command(std::process::Command::new("xdg-open").arg(url))
 }
 
+/// Opens a link taken from an issue or comment body. On Windows this skips
+/// `cmd /C start`, which would treat `&` and friends in the URL as shell syntax.
+fn open_external_url(url: &str) -> Result<()> {
+    if cfg!(target_os = "windows") {
+        return run_silent_command(
+            std::process::Command::new("rundll32").args(["url.dll,FileProtocolHandler", url]),
+        );
+    }
+    open_url(url)
+}
+
 pub(super) fn run_silent_command(command: &mut std::process::Command) -> Result<()> {
     let status = command
         .stdin(std::process::Stdio::null())
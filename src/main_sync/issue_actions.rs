@@ -8,12 +8,13 @@ pub(crate) fn start_add_comment(
     body: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("comment failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -28,12 +29,14 @@ pub(crate) fn start_add_comment(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: "commented".to_string(),
+                        failed: false,
                     });
                 }
                 Err(error) => {
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("comment failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -49,7 +52,7 @@ pub(crate) fn start_create_issue(
     body: Option<String>,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueCreateFailed { message },
@@ -97,12 +100,13 @@ pub(crate) fn start_update_comment(
     body: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("comment update failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -131,6 +135,7 @@ pub(crate) fn start_update_comment(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("comment update failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -147,12 +152,13 @@ pub(crate) fn start_delete_comment(
     token: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("comment delete failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -182,6 +188,7 @@ pub(crate) fn start_delete_comment(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("comment delete failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -198,12 +205,13 @@ pub(crate) fn start_update_labels(
     event_tx: Sender<AppEvent>,
     labels_display: String,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("label update failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -223,6 +231,7 @@ pub(crate) fn start_update_labels(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("label update failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -239,12 +248,13 @@ pub(crate) fn start_update_assignees(
     event_tx: Sender<AppEvent>,
     assignees_display: String,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("assignee update failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -264,6 +274,7 @@ pub(crate) fn start_update_assignees(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("assignee update failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -278,12 +289,13 @@ pub(crate) fn start_reopen_issue(
     token: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("reopen failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -298,12 +310,14 @@ pub(crate) fn start_reopen_issue(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: "reopened".to_string(),
+                        failed: false,
                     });
                 }
                 Err(error) => {
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("reopen failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -318,12 +332,13 @@ pub(crate) fn start_merge_pull_request(
     token: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number: pull_number,
             message: format!("merge failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result = services.runtime.block_on(async {
@@ -338,12 +353,14 @@ pub(crate) fn start_merge_pull_request(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number: pull_number,
                         message: "merged".to_string(),
+                        failed: false,
                     });
                 }
                 Err(error) => {
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number: pull_number,
                         message: format!("merge failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -359,12 +376,13 @@ pub(crate) fn start_close_issue(
     body: Option<String>,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::IssueUpdated {
             issue_number,
             message: format!("close failed: {}", message),
+            failed: true,
         },
         move |services, event_tx| {
             let result: Result<Option<String>, anyhow::Error> = services.runtime.block_on(async {
@@ -391,18 +409,21 @@ pub(crate) fn start_close_issue(
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("closed (comment failed: {})", comment_error),
+                        failed: true,
                     });
                 }
                 Ok(None) => {
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: "closed".to_string(),
+                        failed: false,
                     });
                 }
                 Err(error) => {
                     let _ = event_tx.send(AppEvent::IssueUpdated {
                         issue_number,
                         message: format!("close failed: {}", error),
+                        failed: true,
                     });
                 }
             }
@@ -12,7 +12,8 @@ pub(super) use issue_actions::{
     start_update_labels,
 };
 pub(super) use poll::{
-    maybe_start_comment_poll, maybe_start_issue_poll, maybe_start_pull_request_files_sync,
+    maybe_resume_polling, maybe_start_comment_poll, maybe_start_github_status_check,
+    maybe_start_issue_poll, maybe_start_pull_request_files_sync,
     maybe_start_pull_request_review_comments_sync, maybe_start_repo_labels_sync,
     maybe_start_repo_permissions_sync, maybe_start_repo_sync,
 };
@@ -5,7 +5,7 @@ pub(crate) fn maybe_start_repo_sync(
     token: &str,
     event_tx: Sender<AppEvent>,
 ) -> Result<()> {
-    if app.syncing() {
+    if app.syncing() || app.polling_paused() {
         return Ok(());
     }
 
@@ -49,6 +49,19 @@ pub(crate) fn maybe_start_repo_permissions_sync(
     app.set_repo_permissions_syncing(true);
 }
 
+pub(crate) fn maybe_start_github_status_check(
+    app: &mut App,
+    token: &str,
+    event_tx: Sender<AppEvent>,
+) {
+    if !app.take_github_status_check_request() {
+        return;
+    }
+
+    super::repo_sync::start_github_status_check(token.to_string(), event_tx);
+    app.set_github_status_checking(true);
+}
+
 pub(crate) fn maybe_start_repo_labels_sync(app: &mut App, token: &str, event_tx: Sender<AppEvent>) {
     if app.repo_labels_syncing() {
         return;
@@ -66,7 +79,23 @@ pub(crate) fn maybe_start_repo_labels_sync(app: &mut App, token: &str, event_tx:
     app.set_repo_labels_syncing(true);
 }
 
+pub(crate) fn maybe_resume_polling(
+    app: &mut App,
+    last_issue_poll: &mut Instant,
+    last_comment_poll: &mut Instant,
+) {
+    if !app.take_polling_resumed() {
+        return;
+    }
+
+    *last_issue_poll = Instant::now();
+    *last_comment_poll = Instant::now();
+}
+
 pub(crate) fn maybe_start_issue_poll(app: &mut App, last_poll: &mut Instant) {
+    if app.polling_paused() {
+        return;
+    }
     if !matches!(
         app.view(),
         View::Issues | View::IssueDetail | View::IssueComments | View::PullRequestFiles
@@ -88,6 +117,9 @@ pub(crate) fn maybe_start_comment_poll(
     event_tx: Sender<AppEvent>,
     last_poll: &mut Instant,
 ) -> Result<()> {
+    if app.polling_paused() {
+        return Ok(());
+    }
     if !matches!(
         app.view(),
         View::IssueDetail | View::IssueComments | View::PullRequestFiles
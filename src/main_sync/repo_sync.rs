@@ -208,3 +208,20 @@ pub(crate) fn start_fetch_repo_permissions(
         },
     );
 }
+
+pub(crate) fn start_github_status_check(token: String, event_tx: Sender<AppEvent>) {
+    spawn_with_services(
+        token,
+        event_tx,
+        |_| AppEvent::GitHubStatusChecked { notice: None },
+        move |services, event_tx| {
+            let components = services
+                .runtime
+                .block_on(async { services.client.service_status().await });
+            let notice = components
+                .ok()
+                .and_then(|components| service_status_notice(&components));
+            let _ = event_tx.send(AppEvent::GitHubStatusChecked { notice });
+        },
+    );
+}
@@ -15,7 +15,7 @@ pub(crate) fn start_create_pull_request_review_comment(
     body: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::PullRequestReviewCommentCreateFailed { issue_id, message },
@@ -78,7 +78,7 @@ pub(crate) fn start_update_pull_request_review_comment(
     body: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::PullRequestReviewCommentUpdateFailed { issue_id, message },
@@ -116,7 +116,7 @@ pub(crate) fn start_delete_pull_request_review_comment(
     token: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::PullRequestReviewCommentDeleteFailed { issue_id, message },
@@ -154,7 +154,7 @@ pub(crate) fn start_toggle_pull_request_review_thread_resolution(
     token: String,
     event_tx: Sender<AppEvent>,
 ) {
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::PullRequestReviewThreadResolutionFailed { issue_id, message },
@@ -197,7 +197,7 @@ pub(crate) fn start_set_pull_request_file_viewed(
     event_tx: Sender<AppEvent>,
 ) {
     let error_path = path.clone();
-    spawn_with_services(
+    spawn_mutation_with_services(
         token,
         event_tx,
         move |message| AppEvent::PullRequestFileViewedUpdateFailed {
@@ -1,6 +1,9 @@
 use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
+use regex::Regex;
+
+use crate::config::TicketLinkRule;
 
 const TEXT: Color = Color::Rgb(226, 231, 238);
 const MUTED: Color = Color::Rgb(119, 131, 149);
@@ -13,40 +16,127 @@ const CODE_BG: Color = Color::Rgb(20, 26, 34);
 #[derive(Debug, Default)]
 pub struct RenderedMarkdown {
     pub lines: Vec<Line<'static>>,
+    pub links: Vec<MarkdownLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// Turns external ticket references (e.g. `JIRA-123`) into links using the
+/// configured `ticket_links` rules. `Config::load` rejects invalid patterns;
+/// any that still reach here are skipped.
+#[derive(Debug, Default)]
+pub struct TicketLinker {
+    rules: Vec<(Regex, String)>,
+}
+
+impl TicketLinker {
+    pub fn new(rules: &[TicketLinkRule]) -> Self {
+        let rules = rules
+            .iter()
+            .filter_map(|rule| {
+                let pattern = Regex::new(rule.pattern.as_str()).ok()?;
+                Some((pattern, rule.url.clone()))
+            })
+            .collect::<Vec<(Regex, String)>>();
+        Self { rules }
+    }
+
+    fn find(&self, text: &str) -> Vec<(usize, usize, String)> {
+        let mut found = Vec::new();
+        for (pattern, url) in &self.rules {
+            for captures in pattern.captures_iter(text) {
+                let Some(whole) = captures.get(0) else {
+                    continue;
+                };
+                if whole.is_empty() {
+                    continue;
+                }
+                let mut expanded = String::new();
+                captures.expand(url.as_str(), &mut expanded);
+                if !is_web_url(expanded.as_str()) {
+                    continue;
+                }
+                found.push((whole.start(), whole.end(), expanded));
+            }
+        }
+        found.sort_by_key(|(start, end, _)| (*start, std::cmp::Reverse(*end)));
+
+        let mut matches: Vec<(usize, usize, String)> = Vec::new();
+        for candidate in found {
+            if matches.last().is_some_and(|last| candidate.0 < last.1) {
+                continue;
+            }
+            matches.push(candidate);
+        }
+        matches
+    }
+}
+
+/// Only absolute http(s) URLs are collected, since link destinations come
+/// from untrusted issue and comment bodies and end up in the system opener.
+fn is_web_url(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    let rest = match lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"))
+    {
+        Some(rest) => rest,
+        None => return false,
+    };
+    !rest.is_empty()
+        && !rest.starts_with('/')
+        && !url
+            .chars()
+            .any(|ch| ch.is_whitespace() || ch.is_control() || matches!(ch, '"' | '<' | '>'))
 }
 
 pub fn render(input: &str) -> RenderedMarkdown {
+    render_with_links(input, &TicketLinker::default())
+}
+
+pub fn render_with_links(input: &str, linker: &TicketLinker) -> RenderedMarkdown {
     let options = Options::ENABLE_STRIKETHROUGH
         | Options::ENABLE_TABLES
         | Options::ENABLE_TASKLISTS
         | Options::ENABLE_FOOTNOTES;
     let parser = Parser::new_ext(input, options);
 
-    let mut state = RenderState::new();
+    let mut state = RenderState::new(linker);
     for event in parser {
         state.handle(event);
     }
 
+    let links = std::mem::take(&mut state.links);
     let lines = state.finish();
-    RenderedMarkdown { lines }
+    RenderedMarkdown { lines, links }
 }
 
-struct RenderState {
+struct RenderState<'a> {
     lines: Vec<Vec<Span<'static>>>,
     style_stack: Vec<Style>,
     list_depth: usize,
     blockquote_depth: usize,
     in_code_block: bool,
+    linker: &'a TicketLinker,
+    links: Vec<MarkdownLink>,
+    open_link: Option<MarkdownLink>,
 }
 
-impl RenderState {
-    fn new() -> Self {
+impl<'a> RenderState<'a> {
+    fn new(linker: &'a TicketLinker) -> Self {
         Self {
             lines: vec![Vec::new()],
             style_stack: vec![Style::default()],
             list_depth: 0,
             blockquote_depth: 0,
             in_code_block: false,
+            linker,
+            links: Vec::new(),
+            open_link: None,
         }
     }
 
@@ -54,8 +144,20 @@ impl RenderState {
         match event {
             Event::Start(tag) => self.start_tag(tag),
             Event::End(tag) => self.end_tag(tag),
-            Event::Text(text) => self.push_text(text.as_ref()),
+            Event::Text(text) => {
+                if let Some(link) = self.open_link.as_mut() {
+                    link.label.push_str(text.as_ref());
+                    self.push_text(text.as_ref());
+                } else if self.in_code_block {
+                    self.push_text(text.as_ref());
+                } else {
+                    self.push_text_with_tickets(text.as_ref());
+                }
+            }
             Event::Code(text) => {
+                if let Some(link) = self.open_link.as_mut() {
+                    link.label.push_str(text.as_ref());
+                }
                 let style = Style::default().fg(ACCENT_CYAN).bg(CODE_BG);
                 self.push_span(Span::styled(text.into_string(), style));
             }
@@ -109,7 +211,11 @@ impl RenderState {
                 self.new_line();
                 self.push_style(Style::default().fg(ACCENT_GREEN).bg(CODE_BG));
             }
-            Tag::Link { .. } => {
+            Tag::Link { dest_url, .. } => {
+                self.open_link = Some(MarkdownLink {
+                    label: String::new(),
+                    url: dest_url.into_string(),
+                });
                 self.push_style(
                     Style::default()
                         .fg(ACCENT_CYAN)
@@ -150,6 +256,14 @@ impl RenderState {
                 self.new_line();
             }
             TagEnd::Link => {
+                if let Some(mut link) = self.open_link.take()
+                    && is_web_url(link.url.as_str())
+                {
+                    if link.label.trim().is_empty() {
+                        link.label = link.url.clone();
+                    }
+                    self.links.push(link);
+                }
                 self.pop_style();
             }
             TagEnd::Paragraph => {
@@ -208,6 +322,23 @@ impl RenderState {
         self.push_span(Span::styled(text.to_string(), style));
     }
 
+    fn push_text_with_tickets(&mut self, text: &str) {
+        let mut cursor = 0;
+        for (start, end, url) in self.linker.find(text) {
+            self.push_text(&text[cursor..start]);
+            let label = text[start..end].to_string();
+            let style = self.current_style().patch(
+                Style::default()
+                    .fg(ACCENT_CYAN)
+                    .add_modifier(Modifier::UNDERLINED),
+            );
+            self.push_span(Span::styled(label.clone(), style));
+            self.links.push(MarkdownLink { label, url });
+            cursor = end;
+        }
+        self.push_text(&text[cursor..]);
+    }
+
     fn push_span(&mut self, span: Span<'static>) {
         if let Some(line) = self.lines.last_mut() {
             line.push(span);
@@ -235,7 +366,8 @@ fn heading_style(level: HeadingLevel) -> Style {
 
 #[cfg(test)]
 mod tests {
-    use super::render;
+    use super::{MarkdownLink, TicketLinker, render, render_with_links};
+    use crate::config::TicketLinkRule;
 
     #[test]
     fn renders_heading_and_list() {
@@ -252,4 +384,64 @@ mod tests {
         assert!(text.contains("- one"));
         assert!(text.contains("- two"));
     }
+
+    #[test]
+    fn ticket_rules_link_references_outside_code() {
+        let linker = TicketLinker::new(&[
+            TicketLinkRule {
+                pattern: r"JIRA-(\d+)".to_string(),
+                url: "https://acme.atlassian.net/browse/JIRA-$1".to_string(),
+            },
+            TicketLinkRule {
+                pattern: "(".to_string(),
+                url: "https://invalid.example".to_string(),
+            },
+        ]);
+        let rendered = render_with_links(
+            "Fixes JIRA-12, see [docs](https://docs.example) and `JIRA-99`.\n\n```\nJIRA-7\n```",
+            &linker,
+        );
+
+        assert_eq!(
+            rendered.links,
+            vec![
+                MarkdownLink {
+                    label: "JIRA-12".to_string(),
+                    url: "https://acme.atlassian.net/browse/JIRA-12".to_string(),
+                },
+                MarkdownLink {
+                    label: "docs".to_string(),
+                    url: "https://docs.example".to_string(),
+                },
+            ]
+        );
+        assert!(
+            rendered.lines[0]
+                .to_string()
+                .starts_with("Fixes JIRA-12, see docs")
+        );
+    }
+
+    #[test]
+    fn only_absolute_web_links_are_collected() {
+        let linker = TicketLinker::new(&[TicketLinkRule {
+            pattern: r"OPS-\d+".to_string(),
+            url: "file:///etc/$0".to_string(),
+        }]);
+        let rendered = render_with_links(
+            "[a](file:///etc/passwd) [b](#anchor) [c](docs/readme.md) [d](ms-settings:) \
+             [e](https:///nohost) OPS-1 [f](https://a.example/&calc) [g](HTTP://b.example)",
+            &linker,
+        );
+
+        assert_eq!(
+            rendered
+                .links
+                .iter()
+                .map(|link| link.url.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["https://a.example/&calc", "HTTP://b.example"]
+        );
+        assert!(rendered.lines[0].to_string().contains("OPS-1"));
+    }
 }
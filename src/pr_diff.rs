@@ -93,6 +93,49 @@ pub fn parse_patch(patch: Option<&str>) -> Vec<DiffRow> {
     rows
 }
 
+/// Returns the raw patch lines of the hunk that contains `line`, ending at that
+/// line with at most `context` earlier lines, headed by the hunk header.
+pub fn hunk_excerpt(patch: &str, line: i64, old_side: bool, context: usize) -> Option<String> {
+    let mut old_line = 0i64;
+    let mut new_line = 0i64;
+    let mut header = None;
+    let mut hunk_lines: Vec<&str> = Vec::new();
+
+    for raw in patch.lines() {
+        if raw.starts_with("@@") {
+            (old_line, new_line) = parse_hunk_header(raw).unwrap_or((old_line, new_line));
+            header = Some(raw);
+            hunk_lines.clear();
+            continue;
+        }
+
+        let (matches, old_step, new_step) = if raw.starts_with('+') && !raw.starts_with("+++") {
+            (!old_side && new_line == line, 0, 1)
+        } else if raw.starts_with('-') && !raw.starts_with("---") {
+            (old_side && old_line == line, 1, 0)
+        } else if raw.starts_with(' ') {
+            let current = if old_side { old_line } else { new_line };
+            (current == line, 1, 1)
+        } else {
+            (false, 0, 0)
+        };
+        hunk_lines.push(raw);
+        old_line += old_step;
+        new_line += new_step;
+
+        if matches {
+            let start = hunk_lines.len().saturating_sub(context + 1);
+            let mut excerpt = header
+                .map(ToString::to_string)
+                .into_iter()
+                .collect::<Vec<_>>();
+            excerpt.extend(hunk_lines[start..].iter().map(ToString::to_string));
+            return Some(excerpt.join("\n"));
+        }
+    }
+    None
+}
+
 fn flush_change_block(
     rows: &mut Vec<DiffRow>,
     pending_removed: &mut Vec<(i64, String, String)>,
@@ -168,7 +211,7 @@ fn parse_hunk_header(line: &str) -> Option<(i64, i64)> {
 
 #[cfg(test)]
 mod tests {
-    use super::{DiffKind, parse_patch};
+    use super::{DiffKind, hunk_excerpt, parse_patch};
 
     #[test]
     fn parse_patch_extracts_line_numbers_and_kinds() {
@@ -186,6 +229,22 @@ mod tests {
         assert_eq!(rows[3].new_line, Some(22));
     }
 
+    #[test]
+    fn hunk_excerpt_ends_at_commented_line() {
+        let patch =
+            "@@ -1,2 +1,2 @@\n a\n-b\n+c\n@@ -10,3 +10,4 @@\n keep\n-old\n+new\n+more\n tail\n";
+
+        assert_eq!(
+            hunk_excerpt(patch, 11, false, 1).as_deref(),
+            Some("@@ -10,3 +10,4 @@\n-old\n+new")
+        );
+        assert_eq!(
+            hunk_excerpt(patch, 11, true, 5).as_deref(),
+            Some("@@ -10,3 +10,4 @@\n keep\n-old")
+        );
+        assert_eq!(hunk_excerpt(patch, 40, false, 3), None);
+    }
+
     #[test]
     fn parse_patch_aligns_replacement_blocks_line_by_line() {
         let rows = parse_patch(Some(
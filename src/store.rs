@@ -54,6 +54,15 @@ pub struct LocalRepoRow {
     pub last_scanned: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedItemRow {
+    pub owner: String,
+    pub repo: String,
+    pub number: i64,
+    pub title: String,
+    pub state: String,
+}
+
 pub fn db_path() -> PathBuf {
     data_dir().join(APP_DIR_NAME).join(DB_FILE_NAME)
 }
@@ -302,6 +311,51 @@ pub fn list_local_repos(conn: &Connection) -> Result<Vec<LocalRepoRow>> {
     Ok(repos)
 }
 
+pub fn upsert_linked_item(conn: &Connection, item: &LinkedItemRow) -> Result<()> {
+    conn.execute(
+        "
+        INSERT INTO linked_items (owner, repo, number, title, state)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT(owner, repo, number) DO UPDATE SET
+            title = excluded.title,
+            state = excluded.state
+        ",
+        (
+            item.owner.as_str(),
+            item.repo.as_str(),
+            item.number,
+            item.title.as_str(),
+            item.state.as_str(),
+        ),
+    )?;
+    Ok(())
+}
+
+pub fn list_linked_items(conn: &Connection, owner: &str, repo: &str) -> Result<Vec<LinkedItemRow>> {
+    let mut statement = conn.prepare(
+        "
+        SELECT owner, repo, number, title, state
+        FROM linked_items
+        WHERE owner = ?1 AND repo = ?2
+        ",
+    )?;
+    let rows = statement.query_map([owner, repo], |row| {
+        Ok(LinkedItemRow {
+            owner: row.get(0)?,
+            repo: row.get(1)?,
+            number: row.get(2)?,
+            title: row.get(3)?,
+            state: row.get(4)?,
+        })
+    })?;
+
+    let mut items = Vec::new();
+    for row in rows {
+        items.push(row?);
+    }
+    Ok(items)
+}
+
 pub fn get_repo_by_slug(conn: &Connection, owner: &str, repo: &str) -> Result<Option<RepoRow>> {
     let mut statement = conn.prepare(
         "
@@ -530,6 +584,15 @@ fn apply_migrations(conn: &Connection) -> Result<()> {
             last_scanned TEXT,
             PRIMARY KEY (path, remote_name)
         );
+
+        CREATE TABLE IF NOT EXISTS linked_items (
+            owner TEXT NOT NULL,
+            repo TEXT NOT NULL,
+            number INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            state TEXT NOT NULL,
+            PRIMARY KEY (owner, repo, number)
+        );
         ",
     )?;
     add_comment_accessed_column(conn)?;
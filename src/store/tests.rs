@@ -1,7 +1,7 @@
 use super::{
-    CommentRow, IssueRow, LocalRepoRow, RepoRow, comments_for_issue, delete_db_at,
-    get_repo_by_slug, list_issues, list_local_repos, open_db_at, upsert_comment, upsert_issue,
-    upsert_local_repo, upsert_repo,
+    CommentRow, IssueRow, LinkedItemRow, LocalRepoRow, RepoRow, comments_for_issue, delete_db_at,
+    get_repo_by_slug, list_issues, list_linked_items, list_local_repos, open_db_at, upsert_comment,
+    upsert_issue, upsert_linked_item, upsert_local_repo, upsert_repo,
 };
 use std::fs;
 use std::path::PathBuf;
@@ -355,6 +355,38 @@ fn upsert_repo_preserves_existing_sync_state_when_new_values_missing() {
     let _ = fs::remove_dir_all(&dir);
 }
 
+#[test]
+fn upsert_linked_item_inserts_and_updates() {
+    let dir = unique_temp_dir("linked-items");
+    let db_path = dir.join("blippy.db");
+    let conn = open_db_at(&db_path).expect("open db");
+
+    let item = LinkedItemRow {
+        owner: "acme".to_string(),
+        repo: "blippy".to_string(),
+        number: 42,
+        title: "Fix sync".to_string(),
+        state: "open".to_string(),
+    };
+    upsert_linked_item(&conn, &item).expect("insert linked item");
+    let merged = LinkedItemRow {
+        state: "merged".to_string(),
+        ..item.clone()
+    };
+    upsert_linked_item(&conn, &merged).expect("update linked item");
+    let other_repo = LinkedItemRow {
+        repo: "other".to_string(),
+        ..item
+    };
+    upsert_linked_item(&conn, &other_repo).expect("insert other repo item");
+
+    let items = list_linked_items(&conn, "acme", "blippy").expect("list linked items");
+    assert_eq!(items, vec![merged]);
+
+    drop(conn);
+    let _ = fs::remove_dir_all(&dir);
+}
+
 fn unique_temp_dir(label: &str) -> PathBuf {
     let nanos = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::github::{ApiComment, ApiIssue, ApiIssuesPageResult, ApiRepo, GitHubClient};
+use crate::github::{
+    ApiComment, ApiIssue, ApiIssuesPageResult, ApiRepo, GitHubClient, work_item_state,
+};
 use crate::store::{CommentRow, IssueRow, RepoRow};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -67,18 +69,7 @@ pub fn map_issue_to_row(repo_id: i64, issue: &ApiIssue) -> Option<IssueRow> {
         .collect::<Vec<&str>>()
         .join(",");
     let is_pr = issue.pull_request.is_some();
-    let is_merged = is_pr
-        && issue
-            .pull_request
-            .as_ref()
-            .and_then(|pull_request| pull_request.get("merged_at"))
-            .and_then(serde_json::Value::as_str)
-            .is_some();
-    let state = if is_merged {
-        "merged".to_string()
-    } else {
-        issue.state.clone()
-    };
+    let state = work_item_state(issue.state.as_str(), issue.pull_request.as_ref());
     Some(IssueRow {
         id: issue.id,
         repo_id,
@@ -85,6 +85,30 @@ pub const THEMES: [ThemePalette; 3] = [
     },
 ];
 
+const REPO_ACCENTS: [Color; 8] = [
+    Color::Rgb(97, 175, 239),
+    Color::Rgb(152, 195, 121),
+    Color::Rgb(229, 192, 123),
+    Color::Rgb(198, 120, 221),
+    Color::Rgb(86, 182, 194),
+    Color::Rgb(224, 108, 117),
+    Color::Rgb(209, 154, 102),
+    Color::Rgb(171, 178, 255),
+];
+
+/// Picks an accent for `owner/repo` from a fixed palette. FNV-1a keeps the
+/// choice identical across runs and toolchains, unlike `DefaultHasher`.
+pub fn repo_accent(slug: &str) -> Color {
+    let hash = slug
+        .trim()
+        .to_ascii_lowercase()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    REPO_ACCENTS[(hash % REPO_ACCENTS.len() as u64) as usize]
+}
+
 pub fn resolve_theme(name: Option<&str>) -> &'static ThemePalette {
     if let Some(name) = name
         && let Some(theme) = THEMES
@@ -102,7 +126,7 @@ pub fn default_theme() -> &'static ThemePalette {
 
 #[cfg(test)]
 mod tests {
-    use super::{default_theme, resolve_theme};
+    use super::{default_theme, repo_accent, resolve_theme};
 
     #[test]
     fn resolves_known_theme_case_insensitive() {
@@ -115,4 +139,18 @@ mod tests {
         let theme = resolve_theme(Some("unknown"));
         assert_eq!(theme.name, default_theme().name);
     }
+
+    #[test]
+    fn repo_accent_is_stable_and_case_insensitive() {
+        assert_eq!(
+            repo_accent("AksharP5/blippy"),
+            repo_accent("aksharp5/blippy")
+        );
+        assert_eq!(repo_accent("acme/api"), repo_accent("acme/api"));
+        let distinct = ["acme/api", "acme/web", "acme/cli", "other/core"]
+            .iter()
+            .map(|slug| format!("{:?}", repo_accent(slug)))
+            .collect::<std::collections::HashSet<String>>();
+        assert!(distinct.len() > 1);
+    }
 }
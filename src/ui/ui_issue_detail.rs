@@ -188,6 +188,7 @@ pub(super) fn draw_issue_detail(
                     };
                     linked_issue_tui_hit = Some((link_line, prefix_width, open_width));
                     linked_issue_web_hit = Some((link_line, web_offset, web_width));
+                    body_lines.extend(linked_item_summary_lines(app, &linked_issues, theme));
                 }
             } else {
                 let linked_prs = app.linked_pull_requests_for_issue(number);
@@ -242,6 +243,7 @@ pub(super) fn draw_issue_detail(
                     };
                     linked_pr_tui_hit = Some((link_line, prefix_width, open_width));
                     linked_pr_web_hit = Some((link_line, web_offset, web_width));
+                    body_lines.extend(linked_item_summary_lines(app, &linked_prs, theme));
                 }
             }
         }
@@ -261,7 +263,7 @@ pub(super) fn draw_issue_detail(
         body_lines.push(Line::from(format!("updated: {}", updated)));
     }
     body_lines.push(Line::from(""));
-    let rendered_body = markdown::render(body.as_str());
+    let rendered_body = markdown::render_with_links(body.as_str(), app.ticket_linker());
     if rendered_body.lines.is_empty() {
         body_lines.push(Line::from("No description."));
     } else {
@@ -339,7 +341,8 @@ pub(super) fn draw_issue_detail(
                 false,
                 theme,
             ));
-            let rendered_comment = markdown::render(comment.body.as_str());
+            let rendered_comment =
+                markdown::render_with_links(comment.body.as_str(), app.ticket_linker());
             if rendered_comment.lines.is_empty() {
                 side_lines.push(Line::from(""));
             } else {
@@ -589,7 +592,7 @@ pub(super) fn draw_issue_comments(
                 index == app.selected_comment(),
                 theme,
             ));
-            let rendered = markdown::render(comment.body.as_str());
+            let rendered = markdown::render_with_links(comment.body.as_str(), app.ticket_linker());
             if rendered.lines.is_empty() {
                 lines.push(Line::from(""));
             } else {
@@ -82,11 +82,20 @@ pub(super) fn draw_issues(
             Span::raw(query_display.clone()),
         ]),
     ]);
-    let header_block = Block::default()
+    let mut header_block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(theme.border_panel))
         .style(Style::default().bg(theme.bg_panel));
+    if let (Some(owner), Some(repo)) = (app.current_owner(), app.current_repo()) {
+        let repo_color = repo_accent_color(app, owner, repo);
+        header_block = header_block
+            .border_style(Style::default().fg(repo_color))
+            .title(Line::from(Span::styled(
+                format!(" {}/{} ", owner, repo),
+                Style::default().fg(repo_color).add_modifier(Modifier::BOLD),
+            )));
+    }
     let header_area = sections[0].inner(Margin {
         vertical: 0,
         horizontal: 2,
@@ -143,6 +152,7 @@ pub(super) fn draw_issues(
         ui_status_overlay::focus_border(list_focused, theme),
         theme,
     );
+    let search_text = app.issue_query_text();
     let items = if visible_issues.is_empty() {
         if app.issues().is_empty() {
             let message = if item_mode == crate::app::WorkItemMode::PullRequests {
@@ -173,7 +183,7 @@ pub(super) fn draw_issues(
                 } else {
                     issue.labels.as_str()
                 };
-                let line1_spans = vec![
+                let mut line1_spans = vec![
                     Span::styled(
                         if issue.is_pr {
                             format!("PR #{} ", issue.number)
@@ -188,12 +198,18 @@ pub(super) fn draw_issues(
                         format!("[{}] ", issue.state),
                         Style::default().fg(issue_state_color(issue.state.as_str(), theme)),
                     ),
-                    Span::styled(
-                        ellipsize(issue.title.as_str(), 60),
-                        Style::default().fg(theme.text_primary),
-                    ),
-                    pending_issue_span(app.pending_issue_badge(issue.number), theme),
                 ];
+                let title_style = Style::default().fg(theme.text_primary);
+                line1_spans.extend(fuzzy_highlight_spans(
+                    ellipsize(issue.title.as_str(), 60).as_str(),
+                    search_text.as_str(),
+                    title_style,
+                    fuzzy_highlight_style(title_style, theme),
+                ));
+                line1_spans.push(pending_issue_span(
+                    app.pending_issue_badge(issue.number),
+                    theme,
+                ));
                 let line1 = Line::from(line1_spans);
                 let mut line2_spans = Vec::new();
                 if issue.is_pr {
@@ -393,6 +409,7 @@ pub(super) fn draw_issues(
                         prefix_width.saturating_add(open_width).saturating_add(1),
                         web_width,
                     ));
+                    lines.extend(linked_item_summary_lines(app, &linked_prs, theme));
                 }
             } else {
                 let linked_issues = app.linked_issues_for_pull_request(issue.number);
@@ -435,6 +452,7 @@ pub(super) fn draw_issues(
                         prefix_width.saturating_add(open_width).saturating_add(1),
                         web_width,
                     ));
+                    lines.extend(linked_item_summary_lines(app, &linked_issues, theme));
                 }
             }
             lines.push(Line::from(vec![
@@ -482,7 +500,7 @@ pub(super) fn draw_issues(
             }
             lines.push(Line::from(""));
 
-            let rendered = markdown::render(issue.body.as_str());
+            let rendered = markdown::render_with_links(issue.body.as_str(), app.ticket_linker());
             if rendered.lines.is_empty() {
                 lines.push(Line::from("No description."));
             } else {
@@ -74,7 +74,7 @@ pub(super) fn draw_label_picker(
                     "[ ]"
                 };
                 let selected = app.label_option_selected(label.as_str());
-                ListItem::new(Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         checked,
                         Style::default().fg(if selected {
@@ -84,15 +84,19 @@ pub(super) fn draw_label_picker(
                         }),
                     ),
                     Span::raw(" "),
-                    Span::styled(
-                        label.clone(),
-                        Style::default().fg(if selected {
-                            theme.text_primary
-                        } else {
-                            theme.text_muted
-                        }),
-                    ),
-                ]))
+                ];
+                let label_style = Style::default().fg(if selected {
+                    theme.text_primary
+                } else {
+                    theme.text_muted
+                });
+                spans.extend(fuzzy_highlight_spans(
+                    label.as_str(),
+                    app.label_query(),
+                    label_style,
+                    fuzzy_highlight_style(label_style, theme),
+                ));
+                ListItem::new(Line::from(spans))
             })
             .collect::<Vec<ListItem>>()
     };
@@ -267,7 +271,7 @@ pub(super) fn draw_assignee_picker(
                     "[ ]"
                 };
                 let selected = app.assignee_option_selected(assignee.as_str());
-                ListItem::new(Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         checked,
                         Style::default().fg(if selected {
@@ -277,15 +281,19 @@ pub(super) fn draw_assignee_picker(
                         }),
                     ),
                     Span::raw(" "),
-                    Span::styled(
-                        assignee.clone(),
-                        Style::default().fg(if selected {
-                            theme.text_primary
-                        } else {
-                            theme.text_muted
-                        }),
-                    ),
-                ]))
+                ];
+                let assignee_style = Style::default().fg(if selected {
+                    theme.text_primary
+                } else {
+                    theme.text_muted
+                });
+                spans.extend(fuzzy_highlight_spans(
+                    assignee.as_str(),
+                    app.assignee_query(),
+                    assignee_style,
+                    fuzzy_highlight_style(assignee_style, theme),
+                ));
+                ListItem::new(Line::from(spans))
             })
             .collect::<Vec<ListItem>>()
     };
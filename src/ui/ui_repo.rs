@@ -91,22 +91,26 @@ pub(super) fn draw_repo_picker(
         app.filtered_repo_rows()
             .iter()
             .map(|repo| {
-                let line1 = Line::from(vec![
-                    Span::styled(
-                        format!("{} / {}", repo.owner, repo.repo),
-                        Style::default()
-                            .fg(theme.text_primary)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw("  "),
-                    Span::styled(
-                        repo.remote_name.to_string(),
-                        Style::default().fg(theme.text_muted),
-                    ),
-                ]);
-                let line2 = Line::from(ellipsize(repo.path.as_str(), 96))
-                    .style(Style::default().fg(theme.text_muted));
-                ListItem::new(vec![line1, line2])
+                let repo_color = repo_accent_color(app, repo.owner.as_str(), repo.repo.as_str());
+                let slug_style = Style::default().fg(repo_color).add_modifier(Modifier::BOLD);
+                let muted_style = Style::default().fg(theme.text_muted);
+                let mut line1_spans = vec![Span::styled("● ", Style::default().fg(repo_color))];
+                line1_spans.extend(fuzzy_highlight_spans(
+                    format!("{}/{}", repo.owner, repo.repo).as_str(),
+                    query,
+                    slug_style,
+                    fuzzy_highlight_style(slug_style, theme),
+                ));
+                line1_spans.push(Span::raw("  "));
+                line1_spans.push(Span::styled(repo.remote_name.to_string(), muted_style));
+                let mut line2_spans = vec![Span::raw("  ")];
+                line2_spans.extend(fuzzy_highlight_spans(
+                    ellipsize(repo.path.as_str(), 96).as_str(),
+                    query,
+                    muted_style,
+                    fuzzy_highlight_style(muted_style, theme),
+                ));
+                ListItem::new(vec![Line::from(line1_spans), Line::from(line2_spans)])
             })
             .collect()
     };
@@ -497,6 +497,35 @@ pub(super) fn pending_issue_span(pending: Option<&str>, theme: &ThemePalette) ->
     }
 }
 
+pub(super) fn linked_item_summary_lines(
+    app: &App,
+    numbers: &[i64],
+    theme: &ThemePalette,
+) -> Vec<Line<'static>> {
+    numbers
+        .iter()
+        .filter_map(|number| {
+            let summary = app.linked_item_summary(*number)?;
+            Some(Line::from(vec![
+                Span::styled(
+                    format!("  #{} ", number),
+                    Style::default()
+                        .fg(theme.accent_primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("[{}] ", summary.state),
+                    Style::default().fg(issue_state_color(summary.state.as_str(), theme)),
+                ),
+                Span::styled(
+                    ellipsize(summary.title.as_str(), 60),
+                    Style::default().fg(theme.text_primary),
+                ),
+            ]))
+        })
+        .collect::<Vec<Line<'static>>>()
+}
+
 pub(super) fn label_chip_spans(
     app: &App,
     labels_csv: &str,
@@ -564,6 +593,13 @@ pub(super) fn label_chip_colors(
     (background, theme.bg_app)
 }
 
+pub(super) fn repo_accent_color(app: &App, owner: &str, repo: &str) -> Color {
+    if let Some((red, green, blue)) = parse_hex_color(app.configured_repo_color(owner, repo)) {
+        return Color::Rgb(red, green, blue);
+    }
+    crate::theme::repo_accent(format!("{}/{}", owner, repo).as_str())
+}
+
 pub(super) fn parse_hex_color(value: Option<&str>) -> Option<(u8, u8, u8)> {
     let value = value?.trim().trim_start_matches('#');
     if value.len() != 6 {
@@ -592,6 +628,44 @@ pub(super) fn wrapped_line_count(lines: &[Line<'_>], width: u16) -> usize {
         .sum()
 }
 
+pub(super) fn fuzzy_highlight_spans(
+    text: &str,
+    query: &str,
+    style: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    let positions = crate::fuzzy::fuzzy_match(query, text)
+        .map(|matched| matched.positions)
+        .unwrap_or_default();
+    if query.trim().is_empty() || positions.is_empty() {
+        return vec![Span::styled(text.to_string(), style)];
+    }
+
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_highlighted = false;
+    for (index, ch) in text.chars().enumerate() {
+        let highlighted = positions.binary_search(&index).is_ok();
+        if highlighted != run_highlighted && !run.is_empty() {
+            let run_style = if run_highlighted { highlight } else { style };
+            spans.push(Span::styled(std::mem::take(&mut run), run_style));
+        }
+        run_highlighted = highlighted;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        let run_style = if run_highlighted { highlight } else { style };
+        spans.push(Span::styled(run, run_style));
+    }
+    spans
+}
+
+pub(super) fn fuzzy_highlight_style(style: Style, theme: &ThemePalette) -> Style {
+    style
+        .fg(theme.accent_primary)
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+}
+
 pub(super) fn ellipsize(input: &str, max: usize) -> String {
     if max == 0 {
         return String::new();
@@ -683,3 +757,36 @@ pub(super) fn editor_cursor_position(text: &str) -> (u16, u16) {
     }
     (row, col)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::config::Config;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn issue_title_highlight_matches_filter_case_folding() {
+        let mut app = App::new(Config::default());
+        app.set_view(View::Issues);
+        app.on_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        for ch in "Sync label:bug".chars() {
+            app.on_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        assert_eq!(app.issue_query_text(), "sync");
+
+        let highlight = Style::default().add_modifier(Modifier::UNDERLINED);
+        let spans = fuzzy_highlight_spans(
+            "fix sync",
+            app.issue_query_text().as_str(),
+            Style::default(),
+            highlight,
+        );
+        let highlighted = spans
+            .iter()
+            .filter(|span| span.style == highlight)
+            .map(|span| span.content.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(highlighted, vec!["sync".to_string()]);
+    }
+}
@@ -27,6 +27,22 @@ pub(super) fn draw_status(frame: &mut Frame<'_>, app: &mut App, area: Rect, them
             .fg(sync_state_color(sync, theme))
             .add_modifier(Modifier::BOLD),
     ));
+    if app.polling_paused() {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            "[polling paused]",
+            Style::default().fg(theme.text_muted),
+        ));
+    }
+    if app.pending_mutations() > 0 {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            format!("[{} pending]", app.pending_mutations()),
+            Style::default()
+                .fg(theme.accent_subtle)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
     if !status_text.is_empty() {
         spans.push(Span::raw(" "));
         spans.push(Span::styled(
@@ -34,6 +50,15 @@ pub(super) fn draw_status(frame: &mut Frame<'_>, app: &mut App, area: Rect, them
             Style::default().fg(theme.text_primary),
         ));
     }
+    if let Some(notice) = app.github_status_notice() {
+        spans.push(Span::styled(" • ", Style::default().fg(theme.border_panel)));
+        spans.push(Span::styled(
+            notice.to_string(),
+            Style::default()
+                .fg(theme.accent_danger)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
     if !help_raw.is_empty() {
         spans.push(Span::styled(" • ", Style::default().fg(theme.border_panel)));
         spans.push(Span::styled(
@@ -41,9 +66,28 @@ pub(super) fn draw_status(frame: &mut Frame<'_>, app: &mut App, area: Rect, them
             Style::default().fg(theme.text_muted),
         ));
     }
-    if !context.is_empty() {
-        spans.push(Span::styled(" • ", Style::default().fg(theme.border_panel)));
-        spans.push(Span::styled(context, Style::default().fg(theme.text_muted)));
+    spans.push(Span::styled(" • ", Style::default().fg(theme.border_panel)));
+    spans.push(Span::styled(
+        "repo: ",
+        Style::default().fg(theme.text_muted),
+    ));
+    match (app.current_owner(), app.current_repo()) {
+        (Some(owner), Some(repo)) => spans.push(Span::styled(
+            format!("{}/{}", owner, repo),
+            Style::default()
+                .fg(repo_accent_color(app, owner, repo))
+                .add_modifier(Modifier::BOLD),
+        )),
+        _ => spans.push(Span::styled(
+            "no repo selected",
+            Style::default().fg(theme.text_muted),
+        )),
+    }
+    for part in context {
+        spans.push(Span::styled(
+            format!("  |  {}", part),
+            Style::default().fg(theme.text_muted),
+        ));
     }
 
     let status_line = Line::from(spans);
@@ -239,6 +283,11 @@ fn help_rows(app: &App) -> Vec<(String, String)> {
                     bind(app, "issue_search"),
                     "Search with qualifiers".to_string(),
                 ),
+                (
+                    bind(app, "copy_issue_checklist"),
+                    "Copy list as checklist".to_string(),
+                ),
+                (bind(app, "open_links"), "Open links in item".to_string()),
             ];
             if !reviewing_pr {
                 rows.insert(7, (bind(app, "create_issue"), "Create issue".to_string()));
@@ -263,6 +312,7 @@ fn help_rows(app: &App) -> Vec<(String, String)> {
                 (bind(app, "open_comments"), "Open comments".to_string()),
                 (back_keys, "Back".to_string()),
                 (bind(app, "open_browser"), "Open in browser".to_string()),
+                (bind(app, "open_links"), "Open links in item".to_string()),
             ];
             if !is_pr {
                 rows.insert(4, (bind(app, "create_issue"), "Create issue".to_string()));
@@ -293,6 +343,7 @@ fn help_rows(app: &App) -> Vec<(String, String)> {
                 (bind(app, "add_comment"), "Add comment".to_string()),
                 (back_keys, "Back".to_string()),
                 (bind(app, "open_browser"), "Open in browser".to_string()),
+                (bind(app, "open_links"), "Open links in item".to_string()),
             ];
             if !is_pr {
                 rows.insert(4, (bind(app, "create_issue"), "Create issue".to_string()));
@@ -348,6 +399,10 @@ fn help_rows(app: &App) -> Vec<(String, String)> {
                         bind(app, "resolve_thread"),
                         "Resolve/reopen thread".to_string(),
                     ),
+                    (
+                        bind(app, "copy_review_comment"),
+                        "Copy comment as Markdown quote".to_string(),
+                    ),
                     (
                         bind(app, "merge_pull_request"),
                         "Merge pull request".to_string(),
@@ -372,6 +427,10 @@ fn help_rows(app: &App) -> Vec<(String, String)> {
                     bind(app, "resolve_thread"),
                     "Resolve/reopen thread".to_string(),
                 ),
+                (
+                    bind(app, "copy_review_comment"),
+                    "Copy comment as Markdown quote".to_string(),
+                ),
                 (
                     bind(app, "merge_pull_request"),
                     "Merge pull request".to_string(),
@@ -875,6 +934,14 @@ fn help_text(app: &App) -> String {
                     );
                 }
             }
+            parts.insert(
+                parts.len() - 1,
+                format!("{} copy checklist", bind(app, "copy_issue_checklist")),
+            );
+            parts.insert(
+                parts.len() - 1,
+                format!("{} links", bind(app, "open_links")),
+            );
             parts.join(" • ")
         }
         View::IssueDetail => {
@@ -894,7 +961,7 @@ fn help_text(app: &App) -> String {
                     )
                 };
                 return format!(
-                    "{} pane • {} scroll • {} on description opens comments • {} on changes opens review • {} comments • {}/{} side in review • {} comment • {} labels • {} assignees • {} reopen • dd close • {} checkout • {} merge • {} • {} refresh • {} back • {} quit",
+                    "{} pane • {} scroll • {} on description opens comments • {} on changes opens review • {} comments • {}/{} side in review • {} comment • {} labels • {} assignees • {} reopen • dd close • {} checkout • {} merge • {} • {} links • {} refresh • {} back • {} quit",
                     pane_keys,
                     move_keys,
                     submit,
@@ -909,6 +976,7 @@ fn help_text(app: &App) -> String {
                     bind(app, "checkout_pr"),
                     bind(app, "merge_pull_request"),
                     linked_hint,
+                    bind(app, "open_links"),
                     bind(app, "refresh"),
                     bind(app, "back_escape"),
                     bind(app, "quit")
@@ -916,7 +984,7 @@ fn help_text(app: &App) -> String {
             }
             if app.selected_issue_has_known_linked_pr() {
                 return format!(
-                    "{} pane • {} scroll • {} on right pane opens comments • {} comments • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} linked PR (TUI) • {} linked PR (web) • {} refresh • {} back • {} quit",
+                    "{} pane • {} scroll • {} on right pane opens comments • {} comments • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} linked PR (TUI) • {} linked PR (web) • {} links • {} refresh • {} back • {} quit",
                     pane_keys,
                     move_keys,
                     submit,
@@ -928,13 +996,14 @@ fn help_text(app: &App) -> String {
                     bind(app, "reopen_issue"),
                     bind(app, "open_linked_pr_tui"),
                     bind(app, "open_linked_pr_browser"),
+                    bind(app, "open_links"),
                     bind(app, "refresh"),
                     bind(app, "back_escape"),
                     bind(app, "quit")
                 );
             }
             format!(
-                "{} pane • {} scroll • {} on right pane opens comments • {} comments • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} refresh • {} back • {} quit",
+                "{} pane • {} scroll • {} on right pane opens comments • {} comments • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} links • {} refresh • {} back • {} quit",
                 pane_keys,
                 move_keys,
                 submit,
@@ -944,6 +1013,7 @@ fn help_text(app: &App) -> String {
                 bind(app, "edit_labels"),
                 bind(app, "edit_assignees"),
                 bind(app, "reopen_issue"),
+                bind(app, "open_links"),
                 bind(app, "refresh"),
                 bind(app, "back_escape"),
                 bind(app, "quit")
@@ -966,7 +1036,7 @@ fn help_text(app: &App) -> String {
                     )
                 };
                 return format!(
-                    "{} comments • {} edit • {} delete • {} comment • {} labels • {} assignees • {} reopen • dd close • {} checkout • {} merge • {} • {} refresh • {} back • {} quit",
+                    "{} comments • {} edit • {} delete • {} comment • {} labels • {} assignees • {} reopen • dd close • {} checkout • {} merge • {} • {} links • {} refresh • {} back • {} quit",
                     move_keys,
                     bind(app, "edit_comment"),
                     bind(app, "delete_comment"),
@@ -977,6 +1047,7 @@ fn help_text(app: &App) -> String {
                     bind(app, "checkout_pr"),
                     bind(app, "merge_pull_request"),
                     linked_hint,
+                    bind(app, "open_links"),
                     bind(app, "refresh"),
                     bind(app, "back_escape"),
                     bind(app, "quit")
@@ -984,7 +1055,7 @@ fn help_text(app: &App) -> String {
             }
             if app.selected_issue_has_known_linked_pr() {
                 return format!(
-                    "{} comments • {} edit • {} delete • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} linked PR (TUI) • {} linked PR (web) • {} refresh • {} back • {} quit",
+                    "{} comments • {} edit • {} delete • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} linked PR (TUI) • {} linked PR (web) • {} links • {} refresh • {} back • {} quit",
                     move_keys,
                     bind(app, "edit_comment"),
                     bind(app, "delete_comment"),
@@ -995,13 +1066,14 @@ fn help_text(app: &App) -> String {
                     bind(app, "reopen_issue"),
                     bind(app, "open_linked_pr_tui"),
                     bind(app, "open_linked_pr_browser"),
+                    bind(app, "open_links"),
                     bind(app, "refresh"),
                     bind(app, "back_escape"),
                     bind(app, "quit")
                 );
             }
             format!(
-                "{} comments • {} edit • {} delete • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} refresh • {} back • {} quit",
+                "{} comments • {} edit • {} delete • {} create issue • {} comment • {} labels • {} assignees • {} reopen • dd close • {} links • {} refresh • {} back • {} quit",
                 move_keys,
                 bind(app, "edit_comment"),
                 bind(app, "delete_comment"),
@@ -1010,6 +1082,7 @@ fn help_text(app: &App) -> String {
                 bind(app, "edit_labels"),
                 bind(app, "edit_assignees"),
                 bind(app, "reopen_issue"),
+                bind(app, "open_links"),
                 bind(app, "refresh"),
                 bind(app, "back_escape"),
                 bind(app, "quit")
@@ -1038,7 +1111,7 @@ fn help_text(app: &App) -> String {
                 format!("{} full diff", submit)
             };
             format!(
-                "{} pane • {} move line • {} • {} collapse hunk • {}/{} pan diff • {} reset pan • {}/{} old/new side • {} visual range • {} add • {} edit • {} delete • {} resolve/reopen • {}/{} cycle line comments • {} refresh • {} checkout • {} merge • {} quit",
+                "{} pane • {} move line • {} • {} collapse hunk • {}/{} pan diff • {} reset pan • {}/{} old/new side • {} visual range • {} add • {} edit • {} delete • {} resolve/reopen • {} copy comment • {}/{} cycle line comments • {} refresh • {} checkout • {} merge • {} quit",
                 pane_keys,
                 move_keys,
                 toggle_hint,
@@ -1053,6 +1126,7 @@ fn help_text(app: &App) -> String {
                 bind(app, "edit_comment"),
                 bind(app, "delete_comment"),
                 bind(app, "resolve_thread"),
+                bind(app, "copy_review_comment"),
                 bind(app, "next_line_comment"),
                 bind(app, "prev_line_comment"),
                 bind(app, "refresh"),
@@ -1126,11 +1200,7 @@ fn help_text(app: &App) -> String {
     }
 }
 
-fn status_context(app: &App) -> String {
-    let repo = match (app.current_owner(), app.current_repo()) {
-        (Some(owner), Some(repo)) => format!("{}/{}", owner, repo),
-        _ => "no repo selected".to_string(),
-    };
+fn status_context(app: &App) -> Vec<String> {
     let sync = sync_state_label(app);
     if app.view() == View::Issues {
         let query = app.issue_query().trim();
@@ -1146,12 +1216,14 @@ fn status_context(app: &App) -> String {
             "browse"
         };
         let item_mode = app.work_item_mode().label();
-        return format!(
-            "repo: {}  |  mode: {} ({})  |  assignee: {}  |  query: {}  |  status: {}",
-            repo, mode, item_mode, assignee, query, sync
-        );
+        return vec![
+            format!("mode: {} ({})", mode, item_mode),
+            format!("assignee: {}", assignee),
+            format!("query: {}", query),
+            format!("status: {}", sync),
+        ];
     }
-    format!("repo: {}  |  status: {}", repo, sync)
+    vec![format!("status: {}", sync)]
 }
 
 fn sync_state_label(app: &App) -> &'static str {
@@ -1185,7 +1257,7 @@ fn sync_state_color(sync: &str, theme: &ThemePalette) -> Color {
 
 #[cfg(test)]
 mod tests {
-    use super::primary_help_text;
+    use super::{primary_help_text, status_context};
     use crate::app::{App, View, WorkItemMode};
     use crate::config::Config;
     use crate::store::IssueRow;
@@ -1263,4 +1335,20 @@ mod tests {
 
         assert!(!text.contains("Shift+N create issue"));
     }
+
+    #[test]
+    fn status_context_parts_carry_no_separators() {
+        let mut app = App::new(Config::default());
+        app.set_view(View::Issues);
+        let parts = status_context(&app);
+        assert_eq!(
+            parts.first().map(String::as_str),
+            Some("mode: browse (issues)")
+        );
+        assert_eq!(parts.last().map(String::as_str), Some("status: idle"));
+        assert!(parts.iter().all(|part| !part.contains('|')));
+
+        app.set_view(View::IssueDetail);
+        assert_eq!(status_context(&app), vec!["status: idle".to_string()]);
+    }
 }